@@ -10,8 +10,22 @@ use fortress_bake::{
 use std::path::PathBuf;
 
 fn main() -> StatusOr<()> {
-    let mut root = PathBuf::new();
-    root.push("..");
-    let input_output = InputOutput::new(root)?;
-    bake::run(input_output)
+    // The bake adds its own inputs. Without any of these, cargo would rerun this on every change to the crate.
+    println!("cargo:rerun-if-changed=build.rs");
+    // Only builds with the bake feature read anything from OUT_DIR.
+    if std::env::var_os("CARGO_FEATURE_BAKE").is_none() {
+        return Ok(());
+    }
+    // The workspace root, wherever cargo was invoked from.
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|e| format!("{:?}", e))?;
+    let root = PathBuf::from(manifest_dir).join("..");
+    // Only embed builds read the archive. Packing it otherwise would rerun this on any change to res/.
+    let pack_archive = std::env::var_os("CARGO_FEATURE_EMBED").is_some();
+    let input_output = InputOutput::new(root, pack_archive)?;
+    let summary = bake::run(&input_output)?;
+    for input in summary.inputs.iter() {
+        println!("cargo:rerun-if-changed={}", input.display());
+    }
+    Ok(())
 }
\ No newline at end of file
@@ -2,10 +2,25 @@
 extern crate fortress;
 extern crate fortress_bake;
 
-use fortress::app::StatusOr;
+use fortress::app::{
+    LaunchMode,
+    LaunchOptions,
+    StatusOr,
+};
 
 mod mains;
 
 fn main() -> StatusOr<()> {
-    mains::app::main()
+    let options = LaunchOptions::from_args(std::env::args().skip(1))?;
+    if options.show_help {
+        println!("{}", fortress::app::launch_options::USAGE);
+        return Ok(());
+    }
+    options.apply_resource_overrides()?;
+
+    match options.mode {
+        LaunchMode::Windowed => mains::app::main(options),
+        LaunchMode::Headless => mains::headless::main(options),
+        LaunchMode::Pack => mains::pack::main(options),
+    }
 }
@@ -1,10 +1,11 @@
 use fortress::app::{
     AppRunner,
+    LaunchOptions,
     StatusOr,
 };
 
-pub fn main() -> StatusOr<()> {
-    let mut app_runner = AppRunner::new()?;
-    let _res = app_runner.run()?;
-    std::process::exit(0);
+pub fn main(options: LaunchOptions) -> StatusOr<()> {
+    let mut app_runner = AppRunner::new(options)?;
+    // Return rather than exit so the runner drops, which saves any replay being recorded.
+    app_runner.run()
 }
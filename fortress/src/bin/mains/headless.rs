@@ -0,0 +1,10 @@
+use fortress::app::{
+    HeadlessRunner,
+    LaunchOptions,
+    StatusOr,
+};
+
+pub fn main(options: LaunchOptions) -> StatusOr<()> {
+    let mut headless_runner = HeadlessRunner::new(options)?;
+    headless_runner.run()
+}
@@ -1 +1,3 @@
 pub mod app;
+pub mod headless;
+pub mod pack;
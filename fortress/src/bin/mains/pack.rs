@@ -0,0 +1,15 @@
+use fortress::{
+    app::{
+        LaunchOptions,
+        StatusOr,
+    },
+    file,
+};
+
+pub fn main(options: LaunchOptions) -> StatusOr<()> {
+    let out_path = options.pack_resources
+        .ok_or(String::from("Pack mode needs an output file"))?;
+    let num_files = file::util::pack_resources(&out_path)?;
+    println!("Packed {} files from {:?} into {:?}", num_files, file::util::resource_base(), out_path);
+    Ok(())
+}
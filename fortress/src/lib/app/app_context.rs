@@ -1,4 +1,7 @@
-use crate::app::StatusOr;
+use crate::app::{
+    StatusOr,
+    WindowMode,
+};
 use gl;
 use glm;
 use sdl2;
@@ -11,17 +14,26 @@ fn find_sdl_gl_driver() -> StatusOr<u32> {
     }
     Err(String::from("Could not find SDL GL driver."))
 }
+
+fn fullscreen_type(window_mode: WindowMode) -> sdl2::video::FullscreenType {
+    match window_mode {
+        WindowMode::Windowed => sdl2::video::FullscreenType::Off,
+        WindowMode::Fullscreen => sdl2::video::FullscreenType::True,
+        WindowMode::Borderless => sdl2::video::FullscreenType::Desktop,
+    }
+}
 pub struct AppContext {
     pub events: sdl2::EventPump,
     pub canvas: sdl2::render::WindowCanvas,
     pub controller_subsystem: sdl2::GameControllerSubsystem,
+    window_mode: WindowMode,
     _video_subsystem: sdl2::VideoSubsystem,
     _gl_context: sdl2::video::GLContext,
     _sdl_context: sdl2::Sdl,
 }
 
 impl AppContext {
-    pub fn new(window_size: (i32, i32)) -> StatusOr<AppContext> {
+    pub fn new(window_size: (i32, i32), window_mode: WindowMode) -> StatusOr<AppContext> {
         let sdl_context = sdl2::init()?;
         let video_subsystem = sdl_context.video()?;
         {
@@ -33,6 +45,8 @@ impl AppContext {
 
         let window = video_subsystem.window("App", window_size.0 as u32, window_size.1 as u32)
             .opengl()
+            .resizable()
+            .allow_highdpi()
             .build()
             .map_err(|err| format!("Error initializing window: {}", err))?;
 
@@ -46,32 +60,54 @@ impl AppContext {
             .build()
             .map_err(|err| format!("Error initializing canvas: {}", err))?;
         canvas.window().gl_set_context_to_current()?;
+        canvas.window_mut().set_fullscreen(fullscreen_type(window_mode))?;
         canvas.window_mut().raise();
 
-        unsafe {
-            gl::Enable(gl::DEPTH_TEST);
-            gl::Enable(gl::BLEND);
-            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
-            gl::Viewport(0, 0, window_size.0, window_size.1);
-        }
-
         let controller_subsystem = sdl_context.game_controller()?;
         controller_subsystem.set_event_state(true);
 
         let events = sdl_context.event_pump()?;
 
-        Ok(AppContext {
+        let app_context = AppContext {
             _sdl_context: sdl_context,
             _gl_context: gl_context,
             _video_subsystem: video_subsystem,
             controller_subsystem,
+            window_mode,
             canvas,
             events
-        })
+        };
+
+        unsafe {
+            gl::Enable(gl::DEPTH_TEST);
+            gl::Enable(gl::BLEND);
+            gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
+        }
+        app_context.reset_viewport();
+
+        Ok(app_context)
     }
 
+    // The drawable size in pixels. Larger than the window size on HiDPI displays.
     pub fn screen_size(&self) -> glm::IVec2 {
-        let (x, y) = self.canvas.window().size();
+        let (x, y) = self.canvas.window().drawable_size();
         glm::ivec2(x as i32, y as i32)
     }
+
+    pub fn reset_viewport(&self) {
+        let screen_size = self.screen_size();
+        unsafe {
+            gl::Viewport(0, 0, screen_size.x, screen_size.y);
+        }
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        self.window_mode
+    }
+
+    pub fn set_window_mode(&mut self, window_mode: WindowMode) -> StatusOr<()> {
+        self.canvas.window_mut().set_fullscreen(fullscreen_type(window_mode))?;
+        self.window_mode = window_mode;
+        Ok(())
+    }
 }
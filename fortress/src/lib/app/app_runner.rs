@@ -3,17 +3,39 @@ use crate::{
         AppContext,
         AppRunnerConfig,
         Clock,
+        FixedTimestep,
+        GameState,
+        GameStateStack,
+        LaunchOptions,
         StatusOr,
+        WindowMode,
     },
     audio::AudioPlayer,
     control::Controller,
+    dimensions::time::DeltaTime,
     file::{
         ConfigWatcher,
         SimpleConfigManager,
     },
-    math::RandGen,
+    math::{
+        RandGen,
+        RandStreams,
+    },
+    profiling::{
+        FrameGraphRenderer,
+        FrameProfiler,
+        ProfileCounter,
+        ProfileSection,
+    },
     render::BloomPipeline,
-    world::WorldState,
+    replay::{
+        ReplayPlayer,
+        ReplayRecorder,
+    },
+    world::{
+        WorldRenderer,
+        WorldState,
+    },
 };
 use gl;
 use sdl2::{
@@ -23,47 +45,78 @@ use sdl2::{
     },
     keyboard::Keycode,
 };
+use std::mem;
 
 pub struct AppRunner {
     audio: AudioPlayer,
     clock: Clock,
+    timestep: FixedTimestep,
     controller: Controller,
-    rng: RandGen,
+    pending_gamepad_events: Vec<Event>,
+    rng: RandStreams,
+    replay_recorder: Option<ReplayRecorder>,
+    replay_player: Option<ReplayPlayer>,
+    game_states: GameStateStack,
     world: WorldState,
+    world_renderer: WorldRenderer,
     config_watcher: ConfigWatcher,
     config: SimpleConfigManager<AppRunnerConfig>,
     bloom_render_pipeline: BloomPipeline,
+    profiler: FrameProfiler,
+    frame_graph_renderer: FrameGraphRenderer,
 
     // Declare AppContext last so its dropped last.
     context: AppContext,
 }
 
 impl AppRunner {
-    pub fn new() -> StatusOr<AppRunner> {
+    pub fn new(options: LaunchOptions) -> StatusOr<AppRunner> {
         let mut config_watcher = ConfigWatcher::new()?;
 
         let config: SimpleConfigManager<AppRunnerConfig> = SimpleConfigManager::from_config_resource(&mut config_watcher, "app.conf")?;
 
         let context = {
             let config = config.get();
-            AppContext::new(config.app.window_size)?
+            AppContext::new(options.window_size.unwrap_or(config.app.window_size), options.window_mode.unwrap_or(config.app.window_mode))?
         };
         let audio = AudioPlayer::new(&mut config_watcher)?;
         let controller = Controller::new(&mut config_watcher)?;
-        let rng = RandGen::new();
-        let world = WorldState::new(&mut config_watcher)?;
+        let replay_player = match options.replay {
+            Some(ref path) => Some(ReplayPlayer::load(path)?),
+            None => None,
+        };
+        // A replay is only deterministic under the seed it was recorded with.
+        let seed = match replay_player {
+            Some(ref player) => player.seed(),
+            None => config.get().app.resolve_rng_seed(options.seed_override).unwrap_or_else(RandGen::time_seed),
+        };
+        println!("RNG seed: {}", seed);
+        let rng = RandStreams::from_seed(seed);
+        let replay_recorder = options.record_replay.clone().map(|path| ReplayRecorder::new(path, seed));
+        let world = WorldState::new(&mut config_watcher, options.map_file())?;
+        let world_renderer = WorldRenderer::new(&mut config_watcher, &world)?;
 
-        let bloom_render_pipeline = BloomPipeline::new(context.screen_size())?;
+        let bloom_render_pipeline = BloomPipeline::new(&mut config_watcher, context.screen_size())?;
+        let profiler = FrameProfiler::new(&mut config_watcher, options.profile_csv.as_ref())?;
+        let frame_graph_renderer = FrameGraphRenderer::new(&mut config_watcher)?;
 
         Ok(AppRunner {
             audio,
             clock: Clock::start(),
+            timestep: FixedTimestep::new(),
             controller,
+            pending_gamepad_events: Vec::new(),
             rng,
+            replay_recorder,
+            replay_player,
+            game_states: GameStateStack::new(GameState::Title),
             world,
+            world_renderer,
             config_watcher,
             config,
             bloom_render_pipeline,
+            profiler,
+            frame_graph_renderer,
             context,
         })
     }
@@ -71,13 +124,23 @@ impl AppRunner {
     pub fn run(&mut self) -> StatusOr<()> {
         let _ = self.clock.restart();
         loop {
-            match self.process_events() {
+            self.profiler.begin_frame();
+            let scope = self.profiler.start(ProfileSection::Events);
+            let events = self.process_events();
+            self.profiler.stop(scope);
+            match events {
                 Err(e) => return Err(e),
                 Ok(false) => return Ok(()),
                 _ => {
-                    self.update();
+                    let frame_dt = self.clock.restart();
+                    self.update(frame_dt);
                     self.draw();
+
+                    let scope = self.profiler.start(ProfileSection::Present);
                     self.context.canvas.present();
+                    self.profiler.stop(scope);
+                    // Sleeping isn't part of the frame's cost.
+                    self.profiler.end_frame();
 
                     let ref config = self.config.get().app;
                     let dt_micros = self.clock.peek().as_microseconds();
@@ -94,41 +157,97 @@ impl AppRunner {
     // Return false on quit.
     fn process_events(&mut self) -> StatusOr<bool> {
         let ref config = self.config.get().app;
-        let mut gamepad_events = Vec::new();
+        let mut resized = false;
+        let mut window_mode_toggle = None;
+        let mut toggle_profiler_graph = false;
+        let mut cycle_config_profile = false;
         for event in self.context.events.poll_iter() {
             match event {
                 Event::Quit { .. } => return Ok(false),
                 Event::KeyDown {keycode: Some(Keycode::Q), ..} if config.enable_quit => return Ok(false),
-                Event::Window { win_event: WindowEvent::Resized(width, height), .. } => {
-                    unsafe { gl::Viewport(0, 0, width, height); }
-                },
+                Event::KeyDown {keycode: Some(Keycode::F11), repeat: false, ..} => window_mode_toggle = Some(WindowMode::Fullscreen),
+                Event::KeyDown {keycode: Some(Keycode::F10), repeat: false, ..} => window_mode_toggle = Some(WindowMode::Borderless),
+                Event::KeyDown {keycode: Some(Keycode::F3), repeat: false, ..} => toggle_profiler_graph = !toggle_profiler_graph,
+                Event::KeyDown {keycode: Some(Keycode::F2), repeat: false, ..} => self.config_watcher.reload_errors().clear(),
+                Event::KeyDown {keycode: Some(Keycode::F4), repeat: false, ..} => cycle_config_profile = true,
+                // SizeChanged also fires for fullscreen switches and moves between displays with different DPI.
+                Event::Window { win_event: WindowEvent::SizeChanged(..), .. } => resized = true,
                   Event::ControllerDeviceAdded {..}
                 | Event::ControllerDeviceRemoved {..}
                 | Event::ControllerAxisMotion {..}
                 | Event::ControllerButtonDown {..}
                 | Event::ControllerButtonUp {..}
                 | Event::ControllerDeviceRemapped {..} => {
-                      gamepad_events.push(event);
+                      self.pending_gamepad_events.push(event);
                   },
                 _ => {}
            }
         }
-        self.controller.ingest_gamepad_events(&self.context.controller_subsystem, gamepad_events);
+
+        if toggle_profiler_graph {
+            self.profiler.toggle_graph();
+        }
+        if cycle_config_profile {
+            let profile = self.config_watcher.cycle_config_profile()?;
+            println!("Config profile: {}", profile.as_deref().unwrap_or("none"));
+        }
+        if let Some(window_mode) = window_mode_toggle {
+            let window_mode = self.context.window_mode().toggle(window_mode);
+            self.context.set_window_mode(window_mode)?;
+            resized = true;
+        }
+        if resized {
+            self.context.reset_viewport();
+            self.bloom_render_pipeline.resize(self.context.screen_size())?;
+        }
         Ok(true)
     }
 
-    fn update(&mut self) {
-        let dt = self.clock.restart();
+    fn update(&mut self, frame_dt: DeltaTime) {
         self.config_watcher.update();
         self.config.update();
-        self.controller.update(&self.context.events);
         self.audio.update();
-        self.world.update(&self.audio, &self.controller, &mut self.rng, dt);
+
+        let num_steps = self.timestep.advance(&self.config.get().timestep, frame_dt);
+        let scope = self.profiler.start(ProfileSection::Simulation);
+        for _ in 0..num_steps {
+            self.step();
+        }
+        self.profiler.stop(scope);
+        self.profiler.add_count(ProfileCounter::SimulationSteps, num_steps);
+
+        let scope = self.profiler.start(ProfileSection::RenderUpdate);
+        self.world_renderer.update(&self.world, frame_dt);
+        self.profiler.stop(scope);
+    }
+
+    fn step(&mut self) {
+        // Gamepad events are held until a step consumes them so no press is lost on frames without a step.
+        let gamepad_events = mem::take(&mut self.pending_gamepad_events);
+        let live_input = self.controller.capture_input(&self.context.controller_subsystem, gamepad_events, &self.context.events);
+
+        // Replayed frames stand in for live input until the replay runs out.
+        let (input, dt) = match self.replay_player.as_mut().and_then(|player| player.next_frame()) {
+            Some(frame) => (frame.input.clone(), frame.dt()),
+            None => {
+                if self.replay_player.take().is_some() {
+                    println!("Replay finished, switching to live input");
+                }
+                (live_input, FixedTimestep::step_dt(&self.config.get().timestep))
+            }
+        };
+
+        if let Some(ref mut recorder) = self.replay_recorder {
+            recorder.record(dt, &input);
+        }
+        self.controller.apply_input(&input);
+        self.game_states.step(&self.audio, &self.controller, &mut self.rng, &mut self.profiler, &mut self.world, dt);
     }
 
     fn draw(&mut self) {
         let screen_size = self.context.screen_size();
-        let color = self.world.clear_color();
+        let interpolation_alpha = self.timestep.interpolation_alpha(&self.config.get().timestep);
+        let color = self.world_renderer.clear_color();
         unsafe {
             gl::ClearColor(color.0, color.1, color.2, 1.0);
             gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
@@ -137,10 +256,18 @@ impl AppRunner {
         // 1. Draw all geometry.
         self.bloom_render_pipeline.begin();
         {
-            self.world.draw(screen_size);
+            let scope = self.profiler.start(ProfileSection::DrawWorld);
+            self.world_renderer.draw(&self.world, self.game_states.top(), &mut self.profiler, screen_size, interpolation_alpha);
+            self.profiler.stop(scope);
         }
+        let scope = self.profiler.start(ProfileSection::Bloom);
         self.bloom_render_pipeline.blur(&self.config.get().bloom);
+        self.profiler.stop(scope);
 
         // 2. Non-geometric superimposed draw calls.
+        if self.profiler.show_graph() {
+            self.frame_graph_renderer.draw(&self.profiler, screen_size);
+            self.world_renderer.draw_profiler_legend(&self.profiler);
+        }
     }
 }
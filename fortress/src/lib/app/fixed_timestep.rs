@@ -0,0 +1,53 @@
+use crate::{
+    app::FixedTimestepConfig,
+    dimensions::time::{
+        DeltaTime,
+        Microseconds,
+    },
+};
+
+// Accumulates wall-clock frame time and hands it out in constant-sized simulation steps.
+pub struct FixedTimestep {
+    accumulated: Microseconds,
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FixedTimestep {
+    pub fn new() -> FixedTimestep {
+        FixedTimestep {
+            accumulated: 0
+        }
+    }
+
+    pub fn step_dt(config: &FixedTimestepConfig) -> DeltaTime {
+        DeltaTime::from_microseconds(config.step_micros)
+    }
+
+    // Returns the number of simulation steps to run this frame.
+    pub fn advance(&mut self, config: &FixedTimestepConfig, frame_dt: DeltaTime) -> usize {
+        let step_micros = std::cmp::max(config.step_micros, 1);
+        let frame_micros = std::cmp::min(frame_dt.as_microseconds(), config.max_frame_micros);
+        self.accumulated += std::cmp::max(frame_micros, 0);
+
+        let num_steps = (self.accumulated / step_micros) as usize;
+        if num_steps > config.max_steps_per_frame {
+            // Spiral-of-death guard: drop the time we can't catch up on rather than falling further behind.
+            self.accumulated %= step_micros;
+            config.max_steps_per_frame
+        } else {
+            self.accumulated -= num_steps as Microseconds * step_micros;
+            num_steps
+        }
+    }
+
+    // How far between the last and next simulation step the rendered frame is, in [0, 1).
+    pub fn interpolation_alpha(&self, config: &FixedTimestepConfig) -> f64 {
+        let step_micros = std::cmp::max(config.step_micros, 1);
+        self.accumulated as f64 / step_micros as f64
+    }
+}
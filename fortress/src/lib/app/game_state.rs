@@ -0,0 +1,139 @@
+use crate::{
+    audio::AudioPlayer,
+    control::{
+        ControlEvent,
+        Controller,
+    },
+    dimensions::time::DeltaTime,
+    hud::GameStateOverlayConfig,
+    math::RandStreams,
+    profiling::FrameProfiler,
+    text::{
+        NamedText,
+        RasterSize,
+        ScreenTextRequest,
+        TextContent,
+        TextRenderer,
+    },
+    world::WorldState,
+};
+use glm;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameState {
+    Title,
+    Playing,
+    Paused,
+    // Asked before leaving a run from the pause screen.
+    ConfirmQuit,
+    Victory,
+}
+
+enum GameStateTransition {
+    Push(GameState),
+    Pop,
+    // Clears the whole stack.
+    Reset(GameState),
+}
+
+impl GameState {
+    // Only Playing steps the world. Every other state freezes it but still draws it.
+    fn handle_input(self, controller: &Controller, world: &mut WorldState) -> Option<GameStateTransition> {
+        match self {
+            GameState::Title if controller.any_just_pressed(ControlEvent::Confirm) => {
+                world.restart();
+                Some(GameStateTransition::Reset(GameState::Playing))
+            },
+            GameState::Playing if controller.any_just_pressed(ControlEvent::Pause) => Some(GameStateTransition::Push(GameState::Paused)),
+            GameState::Paused if controller.any_just_pressed(ControlEvent::Pause) => Some(GameStateTransition::Pop),
+            GameState::Paused if controller.any_just_pressed(ControlEvent::QuitRun) => Some(GameStateTransition::Push(GameState::ConfirmQuit)),
+            GameState::ConfirmQuit if controller.any_just_pressed(ControlEvent::Confirm) => Some(GameStateTransition::Reset(GameState::Title)),
+            GameState::ConfirmQuit if controller.any_just_pressed(ControlEvent::Pause) || controller.any_just_pressed(ControlEvent::QuitRun) =>
+                Some(GameStateTransition::Pop),
+            GameState::Victory if controller.any_just_pressed(ControlEvent::Confirm) => Some(GameStateTransition::Reset(GameState::Title)),
+            _ => None,
+        }
+    }
+
+    fn overlay_text(self) -> Option<(NamedText, NamedText)> {
+        match self {
+            GameState::Title => Some((NamedText::TitleHeading, NamedText::TitlePrompt)),
+            GameState::Playing => None,
+            GameState::Paused => Some((NamedText::PausedHeading, NamedText::PausedPrompt)),
+            GameState::ConfirmQuit => Some((NamedText::QuitHeading, NamedText::QuitPrompt)),
+            GameState::Victory => Some((NamedText::VictoryHeading, NamedText::ContinuePrompt)),
+        }
+    }
+
+    pub fn queue_overlay(self, config: &GameStateOverlayConfig, text: &mut TextRenderer) {
+        if let Some((heading, prompt)) = self.overlay_text() {
+            let color = glm::vec3(config.color.0, config.color.1, config.color.2);
+            text.queue_screen_text(
+                std::iter::once(TextContent::Text(heading)),
+                ScreenTextRequest {
+                    screen_position_percentage: glm::vec3(config.heading_pos.0, config.heading_pos.1, config.heading_pos.2),
+                    raster_size: RasterSize::Large,
+                    color,
+                    alpha: config.alpha,
+                });
+            text.queue_screen_text(
+                std::iter::once(TextContent::Text(prompt)),
+                ScreenTextRequest {
+                    screen_position_percentage: glm::vec3(config.prompt_pos.0, config.prompt_pos.1, config.prompt_pos.2),
+                    raster_size: RasterSize::Medium,
+                    color,
+                    alpha: config.alpha,
+                });
+        }
+    }
+}
+
+// Routes input and simulation steps to whichever state is on top.
+pub struct GameStateStack {
+    states: Vec<GameState>,
+}
+
+impl GameStateStack {
+    pub fn new(initial: GameState) -> Self {
+        GameStateStack {
+            states: vec!(initial),
+        }
+    }
+
+    pub fn top(&self) -> GameState {
+        *self.states.last().expect("Game state stack is never empty")
+    }
+
+    pub fn step(&mut self, audio: &AudioPlayer, controller: &Controller, rng: &mut RandStreams, profiler: &mut FrameProfiler, world: &mut WorldState, dt: DeltaTime) {
+        let state = self.top();
+        let transition = state.handle_input(controller, world);
+        match state {
+            GameState::Playing if transition.is_none() => {
+                world.update(audio, controller, rng, profiler, dt);
+                if world.level_complete() {
+                    self.apply(GameStateTransition::Reset(GameState::Victory));
+                }
+            },
+            _ => world.ingest_controller_events(audio, controller),
+        }
+
+        if let Some(transition) = transition {
+            self.apply(transition);
+        }
+    }
+
+    fn apply(&mut self, transition: GameStateTransition) {
+        match transition {
+            GameStateTransition::Push(state) => self.states.push(state),
+            GameStateTransition::Pop => {
+                if self.states.len() > 1 {
+                    self.states.pop();
+                }
+            },
+            GameStateTransition::Reset(state) => {
+                self.states.clear();
+                self.states.push(state);
+            }
+        }
+    }
+}
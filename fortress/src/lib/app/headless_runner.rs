@@ -0,0 +1,150 @@
+use crate::{
+    app::{
+        AppRunnerConfig,
+        FixedTimestep,
+        GameState,
+        GameStateStack,
+        LaunchOptions,
+        StatusOr,
+    },
+    audio::AudioPlayer,
+    control::{
+        Controller,
+        InputFrame,
+    },
+    dimensions::time::DeltaTime,
+    file::{
+        ConfigWatcher,
+        SimpleConfigManager,
+    },
+    math::{
+        RandGen,
+        RandStreams,
+    },
+    profiling::{
+        FrameProfiler,
+        ProfileCounter,
+        ProfileSection,
+    },
+    replay::{
+        ReplayPlayer,
+        ReplayRecorder,
+    },
+    world::WorldState,
+};
+use sdl2::keyboard::Scancode;
+
+// Steps the simulation without a window, GL context or audio device.
+pub struct HeadlessRunner {
+    audio: AudioPlayer,
+    controller: Controller,
+    rng: RandStreams,
+    replay_recorder: Option<ReplayRecorder>,
+    replay_player: Option<ReplayPlayer>,
+    game_states: GameStateStack,
+    world: WorldState,
+    profiler: FrameProfiler,
+    config_watcher: ConfigWatcher,
+    config: SimpleConfigManager<AppRunnerConfig>,
+}
+
+impl HeadlessRunner {
+    pub fn new(options: LaunchOptions) -> StatusOr<HeadlessRunner> {
+        let mut config_watcher = ConfigWatcher::new()?;
+
+        let config: SimpleConfigManager<AppRunnerConfig> = SimpleConfigManager::from_config_resource(&mut config_watcher, "app.conf")?;
+        let audio = AudioPlayer::silent(&mut config_watcher)?;
+        let controller = Controller::new(&mut config_watcher)?;
+        let replay_player = match options.replay {
+            Some(ref path) => Some(ReplayPlayer::load(path)?),
+            None => None,
+        };
+        let seed = match replay_player {
+            Some(ref player) => player.seed(),
+            None => config.get().app.resolve_rng_seed(options.seed_override).unwrap_or_else(RandGen::time_seed),
+        };
+        println!("RNG seed: {}", seed);
+        let rng = RandStreams::from_seed(seed);
+        let replay_recorder = options.record_replay.clone().map(|path| ReplayRecorder::new(path, seed));
+        let world = WorldState::new(&mut config_watcher, options.map_file())?;
+        let profiler = FrameProfiler::new(&mut config_watcher, options.profile_csv.as_ref())?;
+
+        Ok(HeadlessRunner {
+            audio,
+            controller,
+            rng,
+            replay_recorder,
+            replay_player,
+            game_states: GameStateStack::new(GameState::Title),
+            world,
+            profiler,
+            config_watcher,
+            config,
+        })
+    }
+
+    // Runs a replay to completion, otherwise runs for the number of steps given in app.conf.
+    pub fn run(&mut self) -> StatusOr<()> {
+        if self.replay_player.is_some() {
+            self.run_replay();
+            return Ok(());
+        }
+
+        // Like the game, headless starts on the title screen, so press Confirm to start playing.
+        let dt = FixedTimestep::step_dt(&self.config.get().timestep);
+        let confirm = InputFrame {
+            pressed_scancodes: vec!(Scancode::Return as i32),
+            gamepad_inputs: Vec::new(),
+        };
+        self.step_with_input(dt, &confirm);
+
+        let num_steps = self.config.get().headless.num_steps;
+        self.run_steps(num_steps.saturating_sub(1));
+        Ok(())
+    }
+
+    pub fn run_steps(&mut self, num_steps: usize) {
+        for _ in 0..num_steps {
+            self.step();
+        }
+    }
+
+    pub fn run_replay(&mut self) {
+        while let Some(frame) = self.replay_player.as_mut().and_then(|player| player.next_frame()) {
+            self.step_with_input(frame.dt(), &frame.input);
+        }
+        self.replay_player = None;
+    }
+
+    // Steps by the same fixed timestep as the game.
+    pub fn step(&mut self) {
+        let dt = FixedTimestep::step_dt(&self.config.get().timestep);
+        self.step_with_input(dt, &InputFrame::default());
+    }
+
+    pub fn step_with_input(&mut self, dt: DeltaTime, input: &InputFrame) {
+        self.config_watcher.update();
+        self.config.update();
+        self.audio.update();
+        if let Some(ref mut recorder) = self.replay_recorder {
+            recorder.record(dt, input);
+        }
+        self.controller.apply_input(input);
+
+        self.profiler.begin_frame();
+        let scope = self.profiler.start(ProfileSection::Simulation);
+        // Through the game states, as AppRunner::step does, so replays play out the same with or without a window.
+        self.game_states.step(&self.audio, &self.controller, &mut self.rng, &mut self.profiler, &mut self.world, dt);
+        self.profiler.stop(scope);
+        self.profiler.add_count(ProfileCounter::SimulationSteps, 1);
+        self.profiler.end_frame();
+    }
+
+    pub fn game_state(&self) -> GameState {
+        self.game_states.top()
+    }
+
+    pub fn world(&self) -> &WorldState {
+        &self.world
+    }
+}
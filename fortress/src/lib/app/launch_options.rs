@@ -0,0 +1,140 @@
+use crate::{
+    app::{
+        StatusOr,
+        WindowMode,
+    },
+    file,
+};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: fortress [OPTIONS]
+
+Options:
+  --resource-dir DIR     Use DIR as the resource folder instead of searching for res/ or res.pak.
+                         DIR may be a resource archive (also FORTRESS_RESOURCE_DIR).
+                         Builds with the embed feature fall back to their built-in resources
+  --config-dir DIR       Merge configs in DIR over res/config (also FORTRESS_CONFIG_DIR, ignored by
+                         --pack-resources)
+  --user-config          Merge configs in ~/.config/fortress over res/config
+  --config-profile NAME  Merge configs in res/config/profiles/NAME over res/config
+  --map FILE             Load only the map image FILE instead of every PNG in res/map
+  --window-size WxH      Open a window of W by H pixels
+  --fullscreen           Open an exclusive fullscreen window
+  --borderless           Open a borderless window covering the desktop
+  --seed N               Seed the RNG with N
+  --headless             Step the simulation without a window, GL context or audio
+  --pack-resources FILE  Pack the resource folder into the archive FILE and exit
+  --record FILE          Record input to the replay FILE
+  --replay FILE          Play back the replay FILE
+  --profile-csv FILE     Write per-frame timings to FILE
+  --help                 Print this message";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LaunchMode {
+    #[default]
+    Windowed,
+    Headless,
+    Pack,
+}
+
+// Per-launch settings that don't belong in app.conf.
+#[derive(Default)]
+pub struct LaunchOptions {
+    pub mode: LaunchMode,
+    pub resource_dir: Option<PathBuf>,
+    pub config_override_dir: Option<PathBuf>,
+    pub config_profile: Option<String>,
+    pub map_file: Option<PathBuf>,
+    pub window_size: Option<(i32, i32)>,
+    pub window_mode: Option<WindowMode>,
+    pub seed_override: Option<u64>,
+    pub record_replay: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+    pub profile_csv: Option<PathBuf>,
+    pub pack_resources: Option<PathBuf>,
+    pub show_help: bool,
+}
+
+impl LaunchOptions {
+    // Expects the program name to have been skipped already.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> StatusOr<LaunchOptions> {
+        let mut options = LaunchOptions::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--resource-dir" => options.resource_dir = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--config-dir" => options.config_override_dir = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--user-config" => options.config_override_dir = Some(file::util::user_config_dir()?),
+                "--config-profile" => options.config_profile = Some(Self::value(&arg, args.next())?),
+                "--map" => options.map_file = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--window-size" => options.window_size = Some(Self::parse_window_size(&Self::value(&arg, args.next())?)?),
+                "--fullscreen" => options.window_mode = Some(WindowMode::Fullscreen),
+                "--borderless" => options.window_mode = Some(WindowMode::Borderless),
+                "--seed" => {
+                    let seed = Self::value(&arg, args.next())?;
+                    options.seed_override = Some(seed.parse::<u64>().map_err(|e| format!("Bad --seed {}: {}", seed, e))?);
+                },
+                "--headless" => options.mode = LaunchMode::Headless,
+                "--pack-resources" => {
+                    options.mode = LaunchMode::Pack;
+                    options.pack_resources = Some(PathBuf::from(Self::value(&arg, args.next())?));
+                },
+                "--record" => options.record_replay = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--replay" => options.replay = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--profile-csv" => options.profile_csv = Some(PathBuf::from(Self::value(&arg, args.next())?)),
+                "--help" | "-h" => options.show_help = true,
+                _ => return Err(format!("Unknown argument {}\n\n{}", arg, USAGE)),
+            }
+        }
+        Ok(options)
+    }
+
+    // Must run before anything loads a resource.
+    pub fn apply_resource_overrides(&self) -> StatusOr<()> {
+        #[cfg(feature = "embed")]
+        file::util::embed_resources(include_bytes!(concat!(env!("OUT_DIR"), "/res.pak")))?;
+        file::util::init_resource_base(self.resource_dir.as_deref())?;
+        if let Some(ref dir) = self.config_override_dir() {
+            file::util::set_config_override_dir(dir)?;
+        }
+        if let Some(ref profile) = self.config_profile {
+            file::util::set_config_profile(Some(profile))?;
+        }
+        if let Some(ref map_file) = self.map_file {
+            if !map_file.is_file() {
+                return Err(format!("Map file doesn't exist: {:?}", map_file));
+            }
+        }
+        Ok(())
+    }
+
+    // The user config layer is opt-in. The environment variable only applies to runs of the game, so packed archives
+    // don't depend on the shell they were made in.
+    pub fn config_override_dir(&self) -> Option<PathBuf> {
+        match self.mode {
+            _ if self.config_override_dir.is_some() => self.config_override_dir.clone(),
+            LaunchMode::Pack => None,
+            _ => std::env::var_os(file::util::CONFIG_DIR_ENV_VAR).map(PathBuf::from),
+        }
+    }
+
+    pub fn map_file(&self) -> Option<&PathBuf> {
+        self.map_file.as_ref()
+    }
+
+    fn value(flag: &str, value: Option<String>) -> StatusOr<String> {
+        value.ok_or(format!("{} requires a value", flag))
+    }
+
+    fn parse_window_size(size: &str) -> StatusOr<(i32, i32)> {
+        let (width, height) = size.split_once('x')
+            .ok_or(format!("Bad --window-size {}, expected WxH", size))?;
+        let width = width.parse::<i32>().map_err(|e| format!("Bad window width {}: {}", width, e))?;
+        let height = height.parse::<i32>().map_err(|e| format!("Bad window height {}: {}", height, e))?;
+        if width <= 0 || height <= 0 {
+            return Err(format!("Window size must be positive, got {}x{}", width, height));
+        }
+        Ok((width, height))
+    }
+}
@@ -1,11 +1,23 @@
 pub mod app_context;
 pub mod app_runner;
-pub mod app_runner_config;
 pub mod clock;
+pub mod fixed_timestep;
+pub mod game_state;
+pub mod headless_runner;
+pub mod launch_options;
 
 pub use self::app_context::AppContext;
 pub use self::app_runner::AppRunner;
-pub use self::app_runner_config::AppConfig;
-pub use self::app_runner_config::AppRunnerConfig;
+pub use fortress_bake::app::AppConfig;
+pub use fortress_bake::app::AppRunnerConfig;
+pub use fortress_bake::app::FixedTimestepConfig;
+pub use fortress_bake::app::HeadlessConfig;
 pub use self::clock::Clock;
+pub use self::fixed_timestep::FixedTimestep;
+pub use self::game_state::GameState;
+pub use self::game_state::GameStateStack;
+pub use self::headless_runner::HeadlessRunner;
+pub use self::launch_options::LaunchMode;
+pub use self::launch_options::LaunchOptions;
+pub use fortress_bake::app::WindowMode;
 pub use fortress_bake::app::StatusOr;
@@ -1,4 +0,0 @@
-#[derive(Deserialize)]
-pub struct AudioConfig {
-    pub sound_volume: f64
-}
\ No newline at end of file
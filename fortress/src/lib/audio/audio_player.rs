@@ -5,13 +5,18 @@ use crate::{
         Sound
     },
     file::{
+        self,
         ConfigWatcher,
         SimpleConfigManager
     },
 };
-use sdl2::mixer::{
-    Chunk,
-    self,
+use sdl2::{
+    mixer::{
+        Chunk,
+        LoaderRWops,
+        self,
+    },
+    rwops::RWops,
 };
 use std::collections::HashMap;
 
@@ -19,7 +24,8 @@ const CHUNK_SIZE: i32 = 2048;
 
 pub struct AudioPlayer {
     config: SimpleConfigManager<AudioConfig>,
-    chunks: HashMap<Sound, Chunk>
+    chunks: HashMap<Sound, Chunk>,
+    mixer_open: bool,
 }
 
 impl AudioPlayer {
@@ -28,10 +34,7 @@ impl AudioPlayer {
             .map_err(|e| format!("Error opening audio: {}", e))?;
 
         let sound_chunks: StatusOr<HashMap<Sound, Chunk>> = Sound::all_sounds()
-            .map(|sound| {
-                let path_buf = sound.to_path_buf();
-                Chunk::from_file(path_buf).map(|chunk| (sound, chunk))
-            })
+            .map(|sound| Self::load_chunk(sound).map(|chunk| (sound, chunk)))
             .collect();
 
         let config = SimpleConfigManager::from_config_resource(config_watcher, "audio.conf")?;
@@ -39,12 +42,33 @@ impl AudioPlayer {
 
         Ok(AudioPlayer {
             config,
-            chunks: sound_chunks?
+            chunks: sound_chunks?,
+            mixer_open: true,
+        })
+    }
+
+    // Goes through file::util so sounds can come from the resource archive. The mixer copies the samples.
+    fn load_chunk(sound: Sound) -> StatusOr<Chunk> {
+        let path_buf = sound.to_path_buf();
+        let bytes = file::util::mmap(&path_buf)?;
+        let rwops = RWops::from_bytes(bytes.bytes())?;
+        let chunk = rwops.load_wav()
+            .map_err(|e| format!("Couldn't load sound {:?}: {}", path_buf, e))?;
+        Ok(chunk)
+    }
+
+    // An AudioPlayer that never opens the mixer, for running without SDL.
+    pub fn silent(config_watcher: &mut ConfigWatcher) -> StatusOr<AudioPlayer> {
+        let config = SimpleConfigManager::from_config_resource(config_watcher, "audio.conf")?;
+        Ok(AudioPlayer {
+            config,
+            chunks: HashMap::new(),
+            mixer_open: false,
         })
     }
 
     pub fn update(&mut self) {
-        if self.config.update() {
+        if self.config.update() && self.mixer_open {
             Self::set_volume(self.config.get());
         }
     }
@@ -58,6 +82,13 @@ impl AudioPlayer {
         }
     }
 
+    // Plays the sound named by each animation clip event that names one.
+    pub fn play_clip_events(&self, events: &[&str]) {
+        for sound in events.iter().filter_map(|event| Sound::from_name(event)) {
+            self.play_sound(sound);
+        }
+    }
+
     fn set_volume(config: &AudioConfig) {
         let channel = mixer::Channel::all();
         channel.set_volume((config.sound_volume * mixer::MAX_VOLUME as f64) as i32);
@@ -66,6 +97,8 @@ impl AudioPlayer {
 
 impl Drop for AudioPlayer {
     fn drop(&mut self) {
-        mixer::close_audio();
+        if self.mixer_open {
+            mixer::close_audio();
+        }
     }
 }
\ No newline at end of file
@@ -1,7 +1,5 @@
-pub mod audio_config;
 pub mod audio_player;
-pub mod sound;
 
-pub use self::audio_config::AudioConfig;
+pub use fortress_bake::audio::Sound;
+pub use fortress_bake::audio::AudioConfig;
 pub use self::audio_player::AudioPlayer;
-pub use self::sound::Sound;
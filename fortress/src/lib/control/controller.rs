@@ -5,6 +5,7 @@ use crate::{
         ControllerId,
         ControllerEvent,
         GamepadControls,
+        InputFrame,
         KeyboardControls
     },
     file::{
@@ -31,12 +32,22 @@ impl Controller {
         })
     }
 
-    pub fn ingest_gamepad_events(&mut self, controller_subsystem: &sdl2::GameControllerSubsystem, gamepad_events: Vec<sdl2::event::Event>) {
-        self.gamepad.ingest_gamepad_events(controller_subsystem, gamepad_events);
+    // Samples live input from SDL. Feed the result to apply_input, possibly after recording it.
+    pub fn capture_input(&mut self, controller_subsystem: &sdl2::GameControllerSubsystem, gamepad_events: Vec<sdl2::event::Event>, e: &EventPump) -> InputFrame {
+        let pressed_scancodes = e.keyboard_state()
+            .pressed_scancodes()
+            .map(|scancode| scancode as i32)
+            .collect();
+        let gamepad_inputs = self.gamepad.translate_gamepad_events(controller_subsystem, gamepad_events);
+        InputFrame {
+            pressed_scancodes,
+            gamepad_inputs,
+        }
     }
 
-    pub fn update(&mut self, e: &EventPump) {
-        self.keyboard.update(e);
+    pub fn apply_input(&mut self, input: &InputFrame) {
+        self.gamepad.apply_gamepad_inputs(&input.gamepad_inputs);
+        self.keyboard.update(input.scancodes());
     }
 
     pub fn is_pressed(&self, controller_id: ControllerId, event: ControlEvent) -> bool {
@@ -61,6 +72,11 @@ impl Controller {
         }
     }
 
+    // True if the keyboard or any connected gamepad just pressed the event, joined to a player or not.
+    pub fn any_just_pressed(&self, event: ControlEvent) -> bool {
+        self.keyboard.just_pressed(event) || self.gamepad.any_just_pressed(event)
+    }
+
     pub fn just_released(&self, controller_id: ControllerId, event: ControlEvent) -> bool {
         match controller_id {
             ControllerId::Keyboard => {
@@ -10,10 +10,15 @@ pub enum ControllerEvent {
     GamepadDisconnected(GamepadId),
 }
 
+#[derive(Copy, Clone)]
 pub enum ControlEvent {
     PlayerMove(UpDownLeftRight),
     PlayerFireWeapon,
     PlayerFireSpecial,
     PlayerSwitchHero,
     RedeployEntities,
+    Pause,
+    Confirm,
+    // Ends the run from the pause screen, after a confirmation.
+    QuitRun,
 }
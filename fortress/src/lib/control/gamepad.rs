@@ -4,6 +4,7 @@ use crate::{
         ControllerEvent,
         GamepadConfig,
         GamepadId,
+        GamepadInput,
     },
     dimensions::UpDownLeftRight,
     file::SimpleConfigManager,
@@ -88,6 +89,12 @@ impl GamepadControls {
         }
     }
 
+    pub fn any_just_pressed(&self, event: ControlEvent) -> bool {
+        self.gamepads
+            .keys()
+            .any(|gamepad_id| self.just_pressed(*gamepad_id, event))
+    }
+
     pub fn just_released(&self, gamepad_id: GamepadId, event: ControlEvent) -> bool {
         match self.control_event_to_gamepad_control(event) {
             GamepadControl::ButtonPress(button) => {
@@ -105,19 +112,16 @@ impl GamepadControls {
         &self.controller_events
     }
 
-    pub fn ingest_gamepad_events(&mut self, controller_subsystem: &sdl2::GameControllerSubsystem, gamepad_events: Vec<Event>) {
-        self.config_manager.update();
-        self.controller_events.clear();
-        self.just_released.clear();
-        self.just_pressed.clear();
-
+    // Opens and closes devices as they come and go, and returns the events as replayable inputs.
+    pub fn translate_gamepad_events(&mut self, controller_subsystem: &sdl2::GameControllerSubsystem, gamepad_events: Vec<Event>) -> Vec<GamepadInput> {
+        let mut inputs = Vec::with_capacity(gamepad_events.len());
         for event in gamepad_events.into_iter() {
             match event {
                 Event::ControllerDeviceAdded { which, .. } => {
                     if let Ok(game_controller) = controller_subsystem.open(which) {
                         let gamepad_id = GamepadId::from_u32(game_controller.instance_id() as u32);
                         self.gamepads.insert(gamepad_id, game_controller);
-                        self.controller_events.push(ControllerEvent::GamepadConnected(gamepad_id));
+                        inputs.push(GamepadInput::Connected(gamepad_id));
                     } else {
                         println!("Couldn't open gamepad {}", which);
                     }
@@ -125,38 +129,69 @@ impl GamepadControls {
                 Event::ControllerDeviceRemoved { which, .. } => {
                     let gamepad_id = GamepadId::from_u32(which);
                     self.gamepads.remove(&gamepad_id);
-                    self.controller_events.push(ControllerEvent::GamepadDisconnected(gamepad_id));
+                    inputs.push(GamepadInput::Disconnected(gamepad_id));
                 },
                 Event::ControllerButtonDown { which, button, .. } => {
-                    let gamepad_id = GamepadId::from_u32(which);
-                    let gamepad_button = GamepadButton {
-                        gamepad_id,
-                        button
-                    };
-                    self.currently_pressed.insert(gamepad_button);
-                    self.just_pressed.insert(gamepad_button);
+                    inputs.push(GamepadInput::ButtonDown(GamepadId::from_u32(which), button.string()));
                 },
                 Event::ControllerButtonUp { which, button, .. } => {
-                    let gamepad_id = GamepadId::from_u32(which);
-                    let gamepad_button = GamepadButton {
-                        gamepad_id,
-                        button
-                    };
-                    self.currently_pressed.remove(&gamepad_button);
-                    self.just_released.insert(gamepad_button);
+                    inputs.push(GamepadInput::ButtonUp(GamepadId::from_u32(which), button.string()));
                 },
                 Event::ControllerAxisMotion { which, axis, value, .. } => {
-                    let gamepad_id = GamepadId::from_u32(which);
-                    let gamepad_axis = GamepadAxis {
-                        gamepad_id,
-                        axis
-                    };
                     let normalized_value = f32::from(value) / f32::from(i16::max_value());
-                    self.axes.insert(gamepad_axis, normalized_value);
+                    inputs.push(GamepadInput::AxisMotion(GamepadId::from_u32(which), axis.string(), normalized_value));
                 }
                 _ => {}
             }
         }
+        inputs
+    }
+
+    pub fn apply_gamepad_inputs(&mut self, gamepad_inputs: &[GamepadInput]) {
+        self.config_manager.update();
+        self.controller_events.clear();
+        self.just_released.clear();
+        self.just_pressed.clear();
+
+        for input in gamepad_inputs.iter() {
+            match input {
+                GamepadInput::Connected(gamepad_id) => {
+                    self.controller_events.push(ControllerEvent::GamepadConnected(*gamepad_id));
+                },
+                GamepadInput::Disconnected(gamepad_id) => {
+                    self.controller_events.push(ControllerEvent::GamepadDisconnected(*gamepad_id));
+                },
+                GamepadInput::ButtonDown(gamepad_id, button) => {
+                    if let Some(button) = sdl2::controller::Button::from_string(button) {
+                        let gamepad_button = GamepadButton {
+                            gamepad_id: *gamepad_id,
+                            button
+                        };
+                        self.currently_pressed.insert(gamepad_button);
+                        self.just_pressed.insert(gamepad_button);
+                    }
+                },
+                GamepadInput::ButtonUp(gamepad_id, button) => {
+                    if let Some(button) = sdl2::controller::Button::from_string(button) {
+                        let gamepad_button = GamepadButton {
+                            gamepad_id: *gamepad_id,
+                            button
+                        };
+                        self.currently_pressed.remove(&gamepad_button);
+                        self.just_released.insert(gamepad_button);
+                    }
+                },
+                GamepadInput::AxisMotion(gamepad_id, axis, value) => {
+                    if let Some(axis) = sdl2::controller::Axis::from_string(axis) {
+                        let gamepad_axis = GamepadAxis {
+                            gamepad_id: *gamepad_id,
+                            axis
+                        };
+                        self.axes.insert(gamepad_axis, *value);
+                    }
+                }
+            }
+        }
     }
 
     fn control_event_to_gamepad_control(&self, event: ControlEvent) -> GamepadControl {
@@ -170,6 +205,9 @@ impl GamepadControls {
             ControlEvent::PlayerFireSpecial => GamepadControl::ButtonPress(sdl2::controller::Button::B),
             ControlEvent::PlayerSwitchHero => GamepadControl::ButtonPress(sdl2::controller::Button::Y),
             ControlEvent::RedeployEntities => GamepadControl::ButtonPress(sdl2::controller::Button::Back),
+            ControlEvent::Pause => GamepadControl::ButtonPress(sdl2::controller::Button::Start),
+            ControlEvent::Confirm => GamepadControl::ButtonPress(sdl2::controller::Button::A),
+            ControlEvent::QuitRun => GamepadControl::ButtonPress(sdl2::controller::Button::X),
         }
     }
 }
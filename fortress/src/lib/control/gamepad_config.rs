@@ -1,4 +0,0 @@
-#[derive(Clone, Deserialize)]
-pub struct GamepadConfig {
-    pub axis_threshold: f32,
-}
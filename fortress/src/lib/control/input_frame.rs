@@ -0,0 +1,29 @@
+use crate::control::GamepadId;
+use sdl2::keyboard::Scancode;
+use std::collections::HashSet;
+
+// One simulation step's worth of controller input, decoupled from SDL's EventPump so it can be recorded and replayed.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct InputFrame {
+    pub pressed_scancodes: Vec<i32>,
+    pub gamepad_inputs: Vec<GamepadInput>,
+}
+
+impl InputFrame {
+    pub fn scancodes(&self) -> HashSet<Scancode> {
+        self.pressed_scancodes
+            .iter()
+            .filter_map(|scancode| Scancode::from_i32(*scancode))
+            .collect()
+    }
+}
+
+// Buttons and axes are stored by their SDL mapping names.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum GamepadInput {
+    Connected(GamepadId),
+    Disconnected(GamepadId),
+    ButtonDown(GamepadId, String),
+    ButtonUp(GamepadId, String),
+    AxisMotion(GamepadId, String, f32),
+}
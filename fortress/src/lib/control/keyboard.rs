@@ -2,10 +2,7 @@ use crate::{
     control::ControlEvent,
     dimensions::UpDownLeftRight,
 };
-use sdl2::{
-    EventPump,
-    keyboard::Scancode
-};
+use sdl2::keyboard::Scancode;
 use std::collections::HashSet;
 
 pub struct KeyboardControls {
@@ -31,8 +28,7 @@ impl KeyboardControls {
         }
     }
 
-    pub fn update(&mut self, e: &EventPump) {
-        let currently_pressed: HashSet<_> = e.keyboard_state().pressed_scancodes().collect();
+    pub fn update(&mut self, currently_pressed: HashSet<Scancode>) {
         self.first_time_used.touch(!currently_pressed.is_empty());
         self.just_pressed.clear();
         for scancode in currently_pressed.difference(&self.currently_pressed) {
@@ -76,6 +72,9 @@ impl KeyboardControls {
             ControlEvent::PlayerFireSpecial => Scancode::J,
             ControlEvent::PlayerSwitchHero => Scancode::O,
             ControlEvent::RedeployEntities => Scancode::R,
+            ControlEvent::Pause => Scancode::Escape,
+            ControlEvent::Confirm => Scancode::Return,
+            ControlEvent::QuitRun => Scancode::Backspace,
         }
     }
 }
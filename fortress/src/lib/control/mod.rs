@@ -2,9 +2,9 @@ pub mod controller;
 pub mod controller_id;
 pub mod events;
 pub mod gamepad;
-pub mod gamepad_config;
 pub mod gamepad_id;
 pub mod identified_controller;
+pub mod input_frame;
 pub mod keyboard;
 
 pub use self::controller::Controller;
@@ -13,6 +13,8 @@ pub use self::events::ControlEvent;
 pub use self::events::ControllerEvent;
 pub use self::keyboard::KeyboardControls;
 pub use self::gamepad::GamepadControls;
-pub use self::gamepad_config::GamepadConfig;
+pub use fortress_bake::control::GamepadConfig;
 pub use self::gamepad_id::GamepadId;
 pub use self::identified_controller::IdentifiedController;
+pub use self::input_frame::GamepadInput;
+pub use self::input_frame::InputFrame;
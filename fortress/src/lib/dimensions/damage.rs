@@ -1,9 +1,4 @@
-#[derive(Copy, Clone, Deserialize, Eq, Hash, PartialEq)]
-pub enum Criticality {
-    Normal,
-    Crit,
-}
-
+use crate::dimensions::Criticality;
 
 #[derive(Copy, Clone)]
 pub struct Damage {
@@ -3,19 +3,18 @@ pub mod bounding_squircle;
 pub mod damage;
 pub mod grid_index;
 pub mod health;
-pub mod lr_direction;
 pub mod octo_direction;
 pub mod time;
 pub mod up_down_left_right;
 
 pub use self::attack::Attack;
 pub use self::bounding_squircle::BoundingSquircle;
-pub use self::damage::Criticality;
+pub use fortress_bake::dimensions::Criticality;
 pub use self::damage::Damage;
 pub use self::grid_index::GridDirection;
 pub use self::grid_index::GridIndex;
 pub use self::health::Health;
-pub use self::lr_direction::LrDirection;
+pub use fortress_bake::dimensions::LrDirection;
 pub use self::octo_direction::OctoDirection;
 pub use fortress_bake::dimensions::Reverse;
 pub use self::up_down_left_right::UpDownLeftRight;
@@ -1,3 +1,4 @@
+#[derive(Copy, Clone)]
 pub enum UpDownLeftRight {
   Up, Down, Left, Right
 }
\ No newline at end of file
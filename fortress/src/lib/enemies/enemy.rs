@@ -19,7 +19,10 @@ use crate::{
     items::ItemSystem,
     particles::ParticleSystem,
     physics::PhysicsSimulation,
-    render::LightDependentSpriteRenderer,
+    render::{
+        AnimationClips,
+        LightDependentSpriteRenderer,
+    },
 };
 use nalgebra::{
     Point2,
@@ -43,20 +46,20 @@ impl Enemy {
         }
     }
 
-    pub fn pre_update(&mut self, config: &EnemyConfig, dt: DeltaTime, player_locs: &Vec<Point2<f64>>) {
-        if let Some(enemy_state_machine) = self.enemy_state_machine.pre_update(config, dt, player_locs, &mut self.enemy_state) {
+    pub fn pre_update(&mut self, config: &EnemyConfig, clips: &AnimationClips, audio: &AudioPlayer, dt: DeltaTime, player_locs: &Vec<Point2<f64>>) {
+        if let Some(enemy_state_machine) = self.enemy_state_machine.pre_update(config, clips, audio, dt, player_locs, &mut self.enemy_state) {
             self.enemy_state_machine = enemy_state_machine;
         }
     }
 
-    pub fn post_update(&mut self, config: &EnemyConfig, audio: &AudioPlayer, items: &mut ItemSystem, physics_sim: &mut PhysicsSimulation) {
-        if let Some(enemy_state_machine) = self.enemy_state_machine.post_update(config, audio, &self.enemy_state, items, physics_sim) {
+    pub fn post_update(&mut self, config: &EnemyConfig, clips: &AnimationClips, audio: &AudioPlayer, items: &mut ItemSystem, physics_sim: &mut PhysicsSimulation) {
+        if let Some(enemy_state_machine) = self.enemy_state_machine.post_update(config, clips, audio, &self.enemy_state, items, physics_sim) {
             self.enemy_state_machine = enemy_state_machine;
         }
     }
 
-    pub fn queue_draw(&self, config: &EnemyConfig, sprite_renderer: &mut LightDependentSpriteRenderer) {
-        self.enemy_state_machine.queue_draw(config, &self.enemy_state, sprite_renderer);
+    pub fn queue_draw(&self, config: &EnemyConfig, clips: &AnimationClips, sprite_renderer: &mut LightDependentSpriteRenderer) {
+        self.enemy_state_machine.queue_draw(config, clips, &self.enemy_state, sprite_renderer);
     }
 
     pub fn take_attack(&mut self,
@@ -27,6 +27,7 @@ use crate::{
     particles::ParticleSystem,
     physics::PhysicsSimulation,
     render::{
+        AnimationClips,
         LightDependentSpriteRenderer,
         PointLights,
         ScreenShake,
@@ -41,6 +42,7 @@ use nalgebra::{
 
 pub struct EnemySystem {
     config_manager: SimpleConfigManager<EnemySystemConfig>,
+    clips: AnimationClips,
     generator_spawns: Vec<EnemyGeneratorSpawn>,
     generators: Slab<EnemyGenerator>,
     enemies: Slab<Enemy>,
@@ -50,6 +52,7 @@ pub struct EnemySystem {
 impl EnemySystem {
     pub fn new(config_watcher: &mut ConfigWatcher, generator_spawns: &[Point2<f64>], physics_sim: &mut PhysicsSimulation) -> StatusOr<EnemySystem> {
         let config_manager: SimpleConfigManager<EnemySystemConfig> = SimpleConfigManager::from_config_resource(config_watcher, "enemy.conf")?;
+        let clips = AnimationClips::new(config_watcher)?;
 
         let (generators, enemies, damage_text) = {
             let config = config_manager.get();
@@ -68,6 +71,7 @@ impl EnemySystem {
 
         let mut enemy_system = EnemySystem {
             config_manager,
+            clips,
             generator_spawns,
             generators,
             enemies,
@@ -78,10 +82,11 @@ impl EnemySystem {
         Ok(enemy_system)
     }
 
-    pub fn pre_update(&mut self, controller: &Controller, dt: DeltaTime, player_locs: Vec<Point2<f64>>, physics_sim: &mut PhysicsSimulation) {
+    pub fn pre_update(&mut self, audio: &AudioPlayer, controller: &Controller, dt: DeltaTime, player_locs: Vec<Point2<f64>>, physics_sim: &mut PhysicsSimulation) {
         if self.config_manager.update() || controller.just_pressed(ControllerId::Keyboard, ControlEvent::RedeployEntities) {
             self.redeploy(physics_sim);
         }
+        self.clips.update();
 
         let config = self.config_manager.get();
         for (_key, generator) in self.generators.iter_mut() {
@@ -89,7 +94,7 @@ impl EnemySystem {
         }
 
         for (_key , enemy) in self.enemies.iter_mut() {
-            enemy.pre_update(&config.enemy, dt, &player_locs);
+            enemy.pre_update(&config.enemy, &self.clips, audio, dt, &player_locs);
         }
 
         self.damage_text.pre_update(&config.damage_text, dt);
@@ -105,7 +110,7 @@ impl EnemySystem {
 
         let generators = &mut self.generators;
         self.enemies.retain(|enemy| {
-            enemy.post_update(&config.enemy, audio, items, physics_sim);
+            enemy.post_update(&config.enemy, &self.clips, audio, items, physics_sim);
             let scheduled_for_deletion = enemy.dead();
             if scheduled_for_deletion {
                 generators
@@ -118,6 +123,11 @@ impl EnemySystem {
         });
     }
 
+    // The level is cleared once every generator and every enemy it spawned is dead.
+    pub fn all_cleared(&self) -> bool {
+        !self.generator_spawns.is_empty() && self.generators.is_empty() && self.enemies.is_empty()
+    }
+
     pub fn populate_lights(&self, lights: &mut PointLights) {
         let config = self.config_manager.get();
         let generator_lights = self.generators
@@ -134,7 +144,7 @@ impl EnemySystem {
             generator.queue_draw(&config.generator, light_dependent);
         }
         for (_key, enemy) in self.enemies.iter() {
-            enemy.queue_draw(&config.enemy, light_dependent);
+            enemy.queue_draw(&config.enemy, &self.clips, light_dependent);
         }
         self.damage_text.queue_draw(&config.damage_text, text);
     }
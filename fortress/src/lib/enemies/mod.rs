@@ -3,7 +3,6 @@ pub mod enemy;
 pub mod enemy_generator;
 pub mod enemy_generator_id;
 pub mod enemy_id;
-pub mod enemy_config;
 pub mod enemy_system;
 pub mod state;
 
@@ -13,9 +12,9 @@ pub use self::enemy_generator::EnemyGenerator;
 pub use self::enemy_generator::EnemyGeneratorSpawn;
 pub use self::enemy_generator_id::EnemyGeneratorId;
 pub use self::enemy_id::EnemyId;
-pub use self::enemy_config::DamageTextConfig;
-pub use self::enemy_config::EnemyConfig;
-pub use self::enemy_config::EnemyGeneratorConfig;
-pub use self::enemy_config::EnemySystemConfig;
+pub use fortress_bake::enemies::DamageTextConfig;
+pub use fortress_bake::enemies::EnemyConfig;
+pub use fortress_bake::enemies::EnemyGeneratorConfig;
+pub use fortress_bake::enemies::EnemySystemConfig;
 pub use self::enemy_system::EnemySystem;
 pub use self::state::EnemyState;
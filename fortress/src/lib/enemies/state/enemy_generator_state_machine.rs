@@ -27,10 +27,9 @@ use crate::{
     render::{
         LightDependentSpriteData,
         LightDependentSpriteRenderer,
-        NamedSpriteSheet,
+        NamedSprite,
         PointLight,
         ScreenShake,
-        SpriteSheetFrameId,
     },
 };
 use generational_slab::Slab;
@@ -124,7 +123,7 @@ impl EnemyGeneratorStateMachine {
             sprite_renderer.queue(LightDependentSpriteData {
                 world_center_position,
                 world_half_size,
-                sprite_frame_id: SpriteSheetFrameId::new(String::from("enemy_generator.png"), NamedSpriteSheet::SpriteSheet1),
+                sprite_frame_id: NamedSprite::EnemyGenerator.frame_id(),
                 frame,
                 unit_world_rotation: Vector2::new(0.0, 0.0),
                 reverse: Reverse::none(),
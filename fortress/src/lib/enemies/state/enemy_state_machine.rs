@@ -6,10 +6,7 @@ use crate::{
     dimensions::{
         Attack,
         Reverse,
-        time::{
-            DeltaTime,
-            Microseconds,
-        }
+        time::DeltaTime,
     },
     enemies::{
         DamageTextWriter,
@@ -30,10 +27,12 @@ use crate::{
     },
     physics::PhysicsSimulation,
     render::{
+        AnimationClip,
+        AnimationClips,
+        AnimationPlayer,
         LightDependentSpriteData,
         LightDependentSpriteRenderer,
         NamedSpriteSheet,
-        SpriteSheetFrameId,
     },
 };
 use glm;
@@ -43,32 +42,40 @@ use nalgebra::{
 };
 
 pub enum EnemyStateMachine {
-    Base(EnemyBody, Microseconds),
-    Dying(Option<Point2<f64>>, Microseconds),
+    Base(EnemyBody, AnimationPlayer),
+    Dying(Option<Point2<f64>>, AnimationPlayer),
     Dead
 }
 
 impl EnemyStateMachine {
     pub fn new(body: EnemyBody) -> Self {
-        Self::Base(body, 0)
+        Self::Base(body, AnimationPlayer::new())
     }
 
     pub fn pre_update(&mut self,
                       config: &EnemyConfig,
+                      clips: &AnimationClips,
+                      audio: &AudioPlayer,
                       dt: DeltaTime,
                       player_locs: &Vec<Point2<f64>>,
                       enemy_state: &mut EnemyState) -> Option<Self> {
+        if let Some(clip) = self.clip(config, clips) {
+            match self {
+                Self::Base(_, animation) | Self::Dying(_, animation) => {
+                    let events = animation.update(clip, dt);
+                    audio.play_clip_events(&events);
+                },
+                _ => {},
+            }
+        }
+
         match self {
-            Self::Base(body, time_elapsed) => {
-                *time_elapsed += dt.as_microseconds();
+            Self::Base(body, _) => {
                 body.move_to_target(config, player_locs);
                 if let Some(direction) = body.velocity() {
                     enemy_state.set_facing_dir(direction);
                 }
             },
-            Self::Dying(_, time_elapsed) => {
-                *time_elapsed += dt.as_microseconds();
-            },
             _ => {},
         }
         None
@@ -95,17 +102,23 @@ impl EnemyStateMachine {
 
     pub fn post_update(&mut self,
                        config: &EnemyConfig,
+                       clips: &AnimationClips,
                        audio: &AudioPlayer,
                        enemy_state: &EnemyState,
                        items: &mut ItemSystem,
                        physics_sim: &mut PhysicsSimulation) -> Option<Self> {
+        let finished = match (self.clip(config, clips), &*self) {
+            (Some(clip), Self::Dying(_, animation)) => animation.finished(clip),
+            _ => false,
+        };
+
         match self {
             Self::Base(body, _) if !enemy_state.health().alive() => {
                 audio.play_sound(Sound::EnemyKilled);
                 let position = body.position();
-                Some(Self::Dying(position, 0))
+                Some(Self::Dying(position, AnimationPlayer::new()))
             },
-            Self::Dying(position, time_elapsed) if *time_elapsed >= config.dying_duration_micros => {
+            Self::Dying(position, _) if finished => {
                 if let Some(position) = position {
                     let item_pickup = ItemPickup::new(ItemType::Skull(SkullType::Regular), enemy_state.facing_dir());
                     items.spawn_item(item_pickup, position.clone(), physics_sim);
@@ -116,16 +129,14 @@ impl EnemyStateMachine {
         }
     }
 
-    pub fn queue_draw(&self, config: &EnemyConfig, enemy_state: &EnemyState, sprite_renderer: &mut LightDependentSpriteRenderer) {
-        let image_name = match self {
-            Self::Dying(_, _) => String::from("enemy1_dying.png"),
-            _ => String::from("enemy1.png")
-        };
-
-        let frame = match self {
-            Self::Base(_, time_elapsed) => (*time_elapsed / config.walk_frame_duration_micros) as usize,
-            Self::Dying(_, time_elapsed) => (*time_elapsed / config.dying_frame_duration_micros) as usize,
-            _ => 0,
+    pub fn queue_draw(&self,
+                      config: &EnemyConfig,
+                      clips: &AnimationClips,
+                      enemy_state: &EnemyState,
+                      sprite_renderer: &mut LightDependentSpriteRenderer) {
+        let (clip, animation) = match (self.clip(config, clips), self) {
+            (Some(clip), Self::Base(_, animation)) | (Some(clip), Self::Dying(_, animation)) => (clip, animation),
+            _ => return,
         };
 
         let reverse = if enemy_state.facing_dir().is_left() {
@@ -141,8 +152,8 @@ impl EnemyStateMachine {
             sprite_renderer.queue(LightDependentSpriteData {
                 world_center_position,
                 world_half_size,
-                sprite_frame_id: SpriteSheetFrameId::new(image_name, NamedSpriteSheet::SpriteSheet1),
-                frame,
+                sprite_frame_id: clip.frame_id(NamedSpriteSheet::SpriteSheet1),
+                frame: animation.frame(clip),
                 unit_world_rotation: Vector2::new(0.0, 0.0),
                 reverse,
             });
@@ -156,6 +167,15 @@ impl EnemyStateMachine {
         }
     }
 
+    fn clip<'a>(&self, config: &EnemyConfig, clips: &'a AnimationClips) -> Option<&'a AnimationClip> {
+        let name = match self {
+            Self::Base(_, _) => &config.walk_clip,
+            Self::Dying(_, _) => &config.dying_clip,
+            Self::Dead => return None,
+        };
+        Some(clips.get(NamedSpriteSheet::SpriteSheet1, name))
+    }
+
     fn position(&self) -> Option<Point2<f64>> {
         match self {
             Self::Base(body, _) => body.position(),
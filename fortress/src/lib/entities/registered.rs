@@ -97,9 +97,7 @@ impl RegisteredBody {
     }
 
     pub fn default_position(&self) -> Option<Point2<f64>> {
-        let physics_sim = self.physics_sim.borrow();
-        let body = physics_sim.get_rigid_body(self.handle)?;
-        Some(Point2::from(body.position().translation.vector))
+        self.physics_sim.borrow().body_position(self.handle)
     }
 
     pub fn default_set_position(&mut self, point: Point2<f64>) {
@@ -1,5 +1,17 @@
+pub use fortress_bake::file::AssetReferences;
 pub use fortress_bake::file::Config;
+pub use fortress_bake::file::ConfigError;
+pub use fortress_bake::file::ConfigLint;
 pub use fortress_bake::file::ConfigLoader;
+pub use fortress_bake::file::ConfigValidator;
+pub use fortress_bake::file::ConfigViolation;
 pub use fortress_bake::file::ConfigWatcher;
+pub use fortress_bake::file::DirEvent;
+pub use fortress_bake::file::DirSubscription;
+pub use fortress_bake::file::ReloadError;
+pub use fortress_bake::file::ReloadErrors;
+pub use fortress_bake::file::ResourceArchive;
+pub use fortress_bake::file::ResourceBytes;
 pub use fortress_bake::file::SimpleConfigManager;
+pub use fortress_bake::file::Validate;
 pub use fortress_bake::file::util;
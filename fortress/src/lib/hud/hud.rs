@@ -1,23 +1,35 @@
 use crate::{
-    app::StatusOr,
+    app::{
+        GameState,
+        StatusOr,
+    },
     dimensions::time::DeltaTime,
     file::{
+        self,
         ConfigWatcher,
         SimpleConfigManager,
     },
     hud::{
+        ConfigProfileLabelConfig,
         FrameCounter,
         HudConfig,
         PlayerHudUpdate,
+        ReloadErrorOverlay,
         SkullCounter,
     },
-    text::TextRenderer,
+    text::{
+        RasterSize,
+        ScreenTextRequest,
+        TextRenderer,
+    },
 };
+use glm;
 
 pub struct Hud {
     config: SimpleConfigManager<HudConfig>,
     frames: FrameCounter,
     skulls: SkullCounter,
+    reload_errors: ReloadErrorOverlay,
 }
 
 impl Hud {
@@ -33,6 +45,7 @@ impl Hud {
             config,
             frames,
             skulls: SkullCounter::new(),
+            reload_errors: ReloadErrorOverlay::new(config_watcher.reload_errors()),
         })
     }
 
@@ -49,9 +62,23 @@ impl Hud {
         self.skulls.post_update(&player_hud_update);
     }
 
-    pub fn queue_draw(&self, text: &mut TextRenderer) {
+    pub fn queue_draw(&self, game_state: GameState, text: &mut TextRenderer) {
         let config = self.config.get();
         self.frames.queue_draw(&config.frames, text);
         self.skulls.queue_draw(&config.skulls, text);
+        game_state.queue_overlay(&config.game_state, text);
+        self.reload_errors.queue_draw(&config.reload_errors, text);
+        Self::queue_config_profile(&config.config_profile, text);
+    }
+
+    fn queue_config_profile(config: &ConfigProfileLabelConfig, text: &mut TextRenderer) {
+        if let Some(profile) = file::util::config_profile() {
+            text.queue_screen_raw_text(&format!("Config profile: {} (F4 to cycle)", profile), ScreenTextRequest {
+                screen_position_percentage: glm::vec3(config.screen_pos.0, config.screen_pos.1, config.screen_pos.2),
+                raster_size: RasterSize::Small,
+                color: glm::vec3(config.color.0, config.color.1, config.color.2),
+                alpha: config.alpha,
+            });
+        }
     }
 }
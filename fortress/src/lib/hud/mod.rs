@@ -1,14 +1,18 @@
 pub mod frame_counter;
 pub mod hud;
-pub mod hud_config;
 pub mod player_hud_update;
+pub mod reload_error_overlay;
 pub mod skull_counter;
 
 pub use self::frame_counter::FrameCounter;
 pub use self::hud::Hud;
-pub use self::hud_config::FrameCounterConfig;
-pub use self::hud_config::HudConfig;
-pub use self::hud_config::SkullCounterConfig;
+pub use fortress_bake::hud::ConfigProfileLabelConfig;
+pub use fortress_bake::hud::FrameCounterConfig;
+pub use fortress_bake::hud::GameStateOverlayConfig;
+pub use fortress_bake::hud::HudConfig;
+pub use fortress_bake::hud::ReloadErrorOverlayConfig;
+pub use fortress_bake::hud::SkullCounterConfig;
 pub use self::player_hud_update::IndividualPlayerHudData;
 pub use self::player_hud_update::PlayerHudUpdate;
+pub use self::reload_error_overlay::ReloadErrorOverlay;
 pub use self::skull_counter::SkullCounter;
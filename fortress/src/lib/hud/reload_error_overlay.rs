@@ -0,0 +1,63 @@
+use crate::{
+    file::ReloadErrors,
+    hud::ReloadErrorOverlayConfig,
+    text::{
+        RasterSize,
+        ScreenTextRequest,
+        TextRenderer,
+    },
+};
+use glm;
+
+// Lists config files that failed to reload until they're fixed or dismissed.
+pub struct ReloadErrorOverlay {
+    reload_errors: ReloadErrors,
+}
+
+impl ReloadErrorOverlay {
+    pub fn new(reload_errors: ReloadErrors) -> Self {
+        ReloadErrorOverlay {
+            reload_errors,
+        }
+    }
+
+    pub fn queue_draw(&self, config: &ReloadErrorOverlayConfig, text: &mut TextRenderer) {
+        if self.reload_errors.is_empty() {
+            return;
+        }
+
+        let errors = self.reload_errors.errors();
+        let mut lines = vec!(String::from("Config reload failed (F2 to dismiss)"));
+        for reload_error in errors.iter().rev().take(config.max_errors) {
+            let file_name = reload_error.path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| reload_error.path.to_string_lossy().into_owned());
+            let line = match reload_error.error.line_column {
+                Some(_) => format!("{}:{}", file_name, reload_error.error),
+                None => format!("{}: {}", file_name, reload_error.error),
+            };
+            lines.push(Self::sanitize(&line, config.max_line_chars));
+        }
+        if errors.len() > config.max_errors {
+            lines.push(format!("... and {} more", errors.len() - config.max_errors));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let y = config.screen_pos.1 - config.line_height * i as f32;
+            text.queue_screen_raw_text(line, ScreenTextRequest {
+                screen_position_percentage: glm::vec3(config.screen_pos.0, y, config.screen_pos.2),
+                raster_size: RasterSize::Small,
+                color: glm::vec3(config.color.0, config.color.1, config.color.2),
+                alpha: config.alpha,
+            });
+        }
+    }
+
+    // Parser messages can span lines and hold characters without glyphs.
+    fn sanitize(line: &str, max_chars: usize) -> String {
+        line.chars()
+            .map(|c| if c.is_ascii_graphic() { c } else { ' ' })
+            .take(max_chars)
+            .collect()
+    }
+}
@@ -21,8 +21,7 @@ use crate::{
     render::{
         FullyIlluminatedSpriteData,
         FullyIlluminatedSpriteRenderer,
-        NamedSpriteSheet,
-        SpriteSheetFrameId,
+        NamedSprite,
     },
 };
 use nalgebra::{
@@ -60,7 +59,7 @@ impl Barrel {
             renderer.queue(Some(FullyIlluminatedSpriteData {
                 world_center_position,
                 world_half_size,
-                sprite_frame_id: SpriteSheetFrameId::new(String::from("jar.png"), NamedSpriteSheet::SpriteSheet1),
+                sprite_frame_id: NamedSprite::Jar.frame_id(),
                 frame: 0,
                 unit_world_rotation: Vector2::new(0.0, 0.0),
                 reverse: Reverse::none(),
@@ -1,9 +1,8 @@
 pub mod barrel;
 pub mod barrel_body;
-pub mod barrel_config;
 pub mod barrel_id;
 
 pub use self::barrel::Barrel;
 pub use self::barrel_body::BarrelBody;
-pub use self::barrel_config::BarrelConfig;
+pub use fortress_bake::items::BarrelConfig;
 pub use self::barrel_id::BarrelId;
@@ -6,6 +6,11 @@ use crate::{
     items::{
         ItemConfig,
         ItemType,
+        types::{
+            ItemTier1,
+            ItemTier2,
+            SkullType,
+        },
     },
     math::RandGen,
     render::SpriteSheetFrameId,
@@ -44,8 +49,32 @@ impl ItemPickup {
         Some(glm::vec3(color.0, color.1, color.2))
     }
 
+    // The item types are shared with fortress_bake, which has no RNG, so the odds live here.
     pub fn random(rng: &mut RandGen) -> Self {
-        let direction = LrDirection::random(rng);
-        Self::new(ItemType::random(rng), direction)
+        let direction = if rng.flip_coin(0.5) {
+            LrDirection::Left
+        } else {
+            LrDirection::Right
+        };
+        Self::new(Self::random_item_type(rng), direction)
+    }
+
+    fn random_item_type(rng: &mut RandGen) -> ItemType {
+        if rng.flip_coin(0.75) {
+            match rng.ranged_i64(0, 2) {
+                0 => ItemType::Skull(SkullType::Mega),
+                _ => ItemType::Skull(SkullType::Regular),
+            }
+        } else if rng.flip_coin(0.75) {
+            match rng.ranged_i64(0, 2) {
+                0 => ItemType::Tier1(ItemTier1::CritChanceBoost),
+                _ => ItemType::Tier1(ItemTier1::NormalFiringSpeedBoost),
+            }
+        } else {
+            match rng.ranged_i64(0, 2) {
+                0 => ItemType::Tier2(ItemTier2::CritMultiplierBoost),
+                _ => ItemType::Tier2(ItemTier2::SpecialFiringPeriodBoost),
+            }
+        }
     }
 }
\ No newline at end of file
@@ -1,6 +1,5 @@
 pub mod barrels;
 pub mod item;
-pub mod item_config;
 pub mod item_id;
 pub mod item_pickup;
 pub mod item_system;
@@ -8,7 +7,7 @@ pub mod state;
 pub mod types;
 
 pub use self::item::Item;
-pub use self::item_config::ItemConfig;
+pub use fortress_bake::items::ItemConfig;
 pub use self::item_id::ItemId;
 pub use self::item_pickup::ItemPickup;
 pub use self::item_system::ItemSystem;
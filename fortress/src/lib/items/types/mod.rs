@@ -1,9 +1,4 @@
-pub mod item_tier1;
-pub mod item_tier2;
-pub mod item_type;
-pub mod skull_type;
-
-pub use self::item_tier1::ItemTier1;
-pub use self::item_tier2::ItemTier2;
-pub use self::item_type::ItemType;
-pub use self::skull_type::SkullType;
\ No newline at end of file
+pub use fortress_bake::items::types::ItemTier1;
+pub use fortress_bake::items::types::ItemTier2;
+pub use fortress_bake::items::types::ItemType;
+pub use fortress_bake::items::types::SkullType;
@@ -13,6 +13,9 @@ extern crate sdl2;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
 
+#[cfg(not(any(feature = "bake", feature = "rasterize")))]
+compile_error!("Glyphs are either baked with the bake feature or rasterized at runtime with the rasterize feature");
+
 pub mod app;
 pub mod audio;
 pub mod control;
@@ -28,7 +31,9 @@ pub mod math;
 pub mod particles;
 pub mod physics;
 pub mod players;
+pub mod profiling;
 pub mod render;
+pub mod replay;
 pub mod text;
 pub mod weapons;
 pub mod world;
@@ -3,12 +3,16 @@ use crate::{
     dimensions::GridIndex,
     file::{
         Config,
+        ConfigError,
         ConfigWatcher,
-        SimpleConfigManager,
+        DirEvent,
+        DirSubscription,
         self,
     },
-    maps::MapFileConfig,
-    render::Png,
+    maps::{
+        MapFileConfig,
+        MapFileFragment,
+    },
 };
 use std::path::PathBuf;
 
@@ -28,7 +32,6 @@ impl MapFile {
         let mut enemy_generator = Vec::with_capacity(config.generators_count_guess);
         let mut barrels = Vec::with_capacity(config.barrel_count_guess);
 
-        // TODO: in the future, there will be more than one fragment.
         let mut num_fragments = 0;
         for fragment in fragments {
             num_fragments += 1;
@@ -68,9 +71,7 @@ impl MapFile {
             }
         }
 
-        if num_fragments > 1 {
-            return Err(format!("More than one map fragment ({})", num_fragments));
-        }
+        MapFileFragment::check_num_fragments(num_fragments)?;
 
         Ok(MapFile {
             terrain,
@@ -102,85 +103,137 @@ impl MapFile {
     }
 }
 
-// In theory, we construct a MapFile from multiple images.
-struct MapFileFragment {
-    image: Png,
-}
-
-impl Config for MapFileFragment {
-    fn from_path(path_buf: &PathBuf) -> StatusOr<Self> {
-        Ok(MapFileFragment {
-            image: Png::from_file(path_buf)?
-        })
-    }
-}
-
 pub struct MapFileManager {
-    fragment_managers: Vec<SimpleConfigManager<MapFileFragment>>,
+    subscription: DirSubscription,
+    // Set when a single map file was given, so other PNGs in its dir are ignored.
+    only_file: Option<PathBuf>,
+    fragments: Vec<(PathBuf, MapFileFragment)>,
     map_file: MapFile
 }
 
 impl MapFileManager {
-    pub fn new(config: &MapFileConfig, config_watcher: &mut ConfigWatcher) -> StatusOr<MapFileManager> {
-        let mut map_dir = file::util::resource_base();
-        map_dir.push("map");
-
-        let dir_contents =
-            map_dir
-            .read_dir()
-            .map_err(|e| format!("MapFileManager: {:?}", e))?
-            .filter_map(std::result::Result::ok)
-            .map(|entry| entry.path())
-            .filter(|path: &PathBuf| {
-                if let Some(os_base_name) = path.file_name() {
-                    if let Some(base_name) = os_base_name.to_str() {
-                        if base_name.ends_with(".png") {
-                            return true;
-                        }
-                    }
-                }
-                false
-            });
+    // Loads every PNG in res/map unless a single map file is given. PNGs added to res/map later are picked up by update.
+    pub fn new(config: &MapFileConfig, map_file: Option<&PathBuf>, config_watcher: &mut ConfigWatcher) -> StatusOr<MapFileManager> {
+        let (subscription, only_file) = match map_file {
+            Some(path) => {
+                let path = path.canonicalize()
+                    .map_err(|e| format!("Couldn't canonicalize map file {:?}: {}", path, e))?;
+                let dir = path.parent()
+                    .ok_or(format!("Map file has no parent dir: {:?}", path))?;
+                (config_watcher.subscribe_dir(dir, "png")?, Some(path))
+            },
+            None => {
+                let mut map_dir = file::util::resource_base();
+                map_dir.push("map");
+                (config_watcher.subscribe_dir(&map_dir, "png")?, None)
+            },
+        };
 
-        let mut fragment_managers = Vec::with_capacity(config.num_fragments);
-        for path in dir_contents {
-            let fragment_manager = SimpleConfigManager::<MapFileFragment>::from_resource_path(config_watcher, path)?;
-            fragment_managers.push(fragment_manager);
-        }
+        let map_paths = match only_file {
+            Some(ref path) => vec!(path.clone()),
+            None => subscription.files()?,
+        };
 
-        let fragments = fragment_managers
-            .iter()
-            .map(|manager| manager.get());
-        let map_file = MapFile::new(config, fragments)?;
+        let mut fragments = Vec::with_capacity(config.num_fragments);
+        for path in map_paths {
+            let fragment = MapFileFragment::from_path(&path)?;
+            fragments.push((path, fragment));
+        }
+        let map_file = MapFile::new(config, fragments.iter().map(|(_path, fragment)| fragment))?;
 
         Ok(MapFileManager {
-            fragment_managers,
+            subscription,
+            only_file,
+            fragments,
             map_file,
         })
     }
 
     pub fn update(&mut self, config: &MapFileConfig) -> bool {
         let mut dirty = false;
-        for manager in self.fragment_managers.iter_mut() {
-            dirty |= manager.update();
+        for event in self.subscription.take_events() {
+            dirty |= match event {
+                DirEvent::Added(path) | DirEvent::Changed(path) => self.load_fragment(path),
+                DirEvent::Removed(path) => self.remove_fragment(&path),
+                DirEvent::Rescan => self.rescan(),
+            };
         }
 
         if !dirty { return false; }
 
-        let fragments = self.fragment_managers
-            .iter()
-            .map(|manager| manager.get());
-
-        match MapFile::new(config, fragments) {
+        match MapFile::new(config, self.fragments.iter().map(|(_path, fragment)| fragment)) {
             Ok(map_file) => {
+                self.subscription.resolve_error(self.subscription.dir());
                 self.map_file = map_file;
                 true
             },
-            _ => false
+            Err(e) => {
+                self.subscription.report_error(self.subscription.dir().clone(), ConfigError::from(e));
+                false
+            }
         }
     }
 
     pub fn get(&self) -> &MapFile {
         &self.map_file
     }
+
+    fn tracks(&self, path: &PathBuf) -> bool {
+        match self.only_file {
+            Some(ref only_file) => only_file == path,
+            None => true,
+        }
+    }
+
+    // Keeps fragments sorted by path so the map comes out the same regardless of event order.
+    fn load_fragment(&mut self, path: PathBuf) -> bool {
+        if !self.tracks(&path) {
+            return false;
+        }
+
+        match MapFileFragment::from_path(&path) {
+            Err(e) => {
+                self.subscription.report_error(path, e);
+                false
+            },
+            Ok(fragment) => {
+                self.subscription.resolve_error(&path);
+                match self.fragments.binary_search_by(|(existing, _)| existing.cmp(&path)) {
+                    Ok(i) => self.fragments[i].1 = fragment,
+                    Err(i) => self.fragments.insert(i, (path, fragment)),
+                }
+                true
+            },
+        }
+    }
+
+    fn remove_fragment(&mut self, path: &PathBuf) -> bool {
+        self.subscription.resolve_error(path);
+        match self.fragments.iter().position(|(existing, _)| existing == path) {
+            Some(i) => {
+                self.fragments.remove(i);
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn rescan(&mut self) -> bool {
+        let paths = match self.only_file {
+            Some(ref path) => vec!(path.clone()),
+            None => match self.subscription.files() {
+                Ok(paths) => paths,
+                Err(e) => {
+                    self.subscription.report_error(self.subscription.dir().clone(), ConfigError::from(e));
+                    return false;
+                },
+            },
+        };
+
+        self.fragments.retain(|(path, _)| paths.contains(path));
+        for path in paths {
+            self.load_fragment(path);
+        }
+        true
+    }
 }
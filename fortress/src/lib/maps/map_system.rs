@@ -12,49 +12,47 @@ use crate::{
     },
     physics::PhysicsSimulation,
     render::{
-        CameraGeometry,
         CameraStreamInfo,
         FullyIlluminatedSpriteRenderer,
         PointLights,
-        SpriteSheetTextureManager,
     },
 };
 use nalgebra::Point2;
+use std::path::PathBuf;
 
 pub struct MapSystem {
     map_config_manager: SimpleConfigManager<MapConfig>,
     map_file_manager:  MapFileManager,
     map_state: MapState,
-    hex_renderer: HexRenderer,
+    generation: usize,
 }
 
 impl MapSystem {
-    pub fn new(config_watcher: &mut ConfigWatcher, physics_sim: &mut PhysicsSimulation) -> StatusOr<MapSystem> {
+    pub fn new(config_watcher: &mut ConfigWatcher, map_file: Option<&PathBuf>, physics_sim: &mut PhysicsSimulation) -> StatusOr<MapSystem> {
         let map_config_manager = SimpleConfigManager::<MapConfig>::from_config_resource(config_watcher, "map.conf")?;
         let map_file_manager = {
             let config = map_config_manager.get();
-            MapFileManager::new(&config.map_file, config_watcher)?
+            MapFileManager::new(&config.map_file, map_file, config_watcher)?
         };
 
-        let (map_state, hex_renderer) = {
+        let map_state = {
             let config = map_config_manager.get();
             let map_file = map_file_manager.get();
-            let map_state = MapState::new(config, map_file, physics_sim);
-            let hex_renderer = HexRenderer::new(config)?;
-            (map_state, hex_renderer)
+            MapState::new(config, map_file, physics_sim)
         };
 
         Ok(MapSystem {
             map_config_manager,
             map_file_manager,
             map_state,
-            hex_renderer,
+            generation: 0,
         })
     }
 
     pub fn pre_update(&mut self, physics_sim: &mut PhysicsSimulation) -> bool {
         if self.map_config_manager.update() || self.map_file_manager.update(&self.map_config_manager.get().map_file) {
-            self.redeploy(physics_sim).is_ok()
+            self.redeploy(physics_sim);
+            true
         } else {
             false
         }
@@ -65,14 +63,9 @@ impl MapSystem {
         self.map_state.populate_lights(config, lights);
     }
 
-    pub fn queue_draw(&mut self, camera_stream_info: &CameraStreamInfo, sprite_renderer: &mut FullyIlluminatedSpriteRenderer) {
+    pub fn queue_draw(&self, camera_stream_info: &CameraStreamInfo, hex_renderer: &mut HexRenderer, sprite_renderer: &mut FullyIlluminatedSpriteRenderer) {
         let config = self.map_config_manager.get();
-        self.map_state.queue_draw(config, camera_stream_info, &mut self.hex_renderer, sprite_renderer);
-    }
-
-    pub fn draw_terrain(&mut self, textures: &SpriteSheetTextureManager, lights: &PointLights, geometry: &CameraGeometry) {
-        let config = self.map_config_manager.get();
-        self.hex_renderer.draw(config, textures, lights, geometry);
+        self.map_state.queue_draw(config, camera_stream_info, hex_renderer, sprite_renderer);
     }
 
     pub fn spawns(&self) -> &[Point2<f64>] {
@@ -91,11 +84,19 @@ impl MapSystem {
         self.map_config_manager.get().cell_length
     }
 
-    fn redeploy(&mut self, physics_sim: &mut PhysicsSimulation) -> StatusOr<()> {
+    pub fn config(&self) -> &MapConfig {
+        self.map_config_manager.get()
+    }
+
+    // Incremented on every redeploy so renderers know when to rebuild map geometry.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn redeploy(&mut self, physics_sim: &mut PhysicsSimulation) {
         let config = self.map_config_manager.get();
         let map_file = self.map_file_manager.get();
         self.map_state = MapState::new(config, map_file, physics_sim);
-        self.hex_renderer = HexRenderer::new(config)?;
-        Ok(())
+        self.generation += 1;
     }
 }
\ No newline at end of file
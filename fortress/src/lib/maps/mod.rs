@@ -1,12 +1,12 @@
-pub mod map_config;
 pub mod map_file;
 pub mod map_state;
 pub mod map_system;
 pub mod render;
 pub mod state;
 
-pub use self::map_config::MapConfig;
-pub use self::map_config::MapFileConfig;
+pub use fortress_bake::maps::MapConfig;
+pub use fortress_bake::maps::MapFileConfig;
+pub use fortress_bake::maps::MapFileFragment;
 pub use self::map_file::MapFile;
 pub use self::map_file::MapFileManager;
 pub use self::map_state::MapState;
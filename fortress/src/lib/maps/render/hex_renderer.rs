@@ -4,7 +4,10 @@ use crate::{
         GridIndex,
         Reverse,
     },
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     maps::{
         MapConfig,
         render::HexMesh
@@ -14,15 +17,16 @@ use crate::{
         Attribute,
         AttributeProgram,
         CameraGeometry,
+        NamedSprite,
         NamedSpriteSheet,
         PointLights,
         ShaderProgram,
         ShaderUniformKey,
-        SpriteSheetFrameId,
         SpriteSheetTextureManager,
         TextureUnit,
     }
 };
+use gl;
 use glm;
 use std::ffi::CString;
 
@@ -84,11 +88,11 @@ pub struct HexRenderer {
 }
 
 impl HexRenderer {
-    pub fn new(config: &MapConfig) -> StatusOr<HexRenderer> {
+    pub fn new(config_watcher: &mut ConfigWatcher, config: &MapConfig) -> StatusOr<HexRenderer> {
         let vertex = file::util::resource_path("shaders", "hex_vert.glsl");
         let geometry = file::util::resource_path("shaders", "hex_geo.glsl");
         let fragment = file::util::resource_path("shaders", "hex_frag.glsl");
-        let shader_program = ShaderProgram::from_long_pipeline(&vertex, &geometry, &fragment)?;
+        let shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
 
         // The HexMesh will take up the first vertex attrib slot.
         let mut attribute_program_builder = AttributeProgram::builder_with_offset(1);
@@ -109,6 +113,16 @@ impl HexRenderer {
         })
     }
 
+    // Keeps the shader, which is already watched, and only replaces the mesh inside the existing vertex array.
+    pub fn rebuild_mesh(&mut self, config: &MapConfig) {
+        self.attribute_program.activate();
+        self.mesh = HexMesh::new(config);
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+        }
+        self.attribute_program.deactivate();
+    }
+
     pub fn queue(&mut self, hex_cell_length: f64, data: impl Iterator<Item = HexData>) {
         let axial_to_cartesian = GridIndex::axial_to_cartesian(hex_cell_length);
 
@@ -129,6 +143,7 @@ impl HexRenderer {
     }
 
     pub fn draw(&mut self, config: &MapConfig, textures: &SpriteSheetTextureManager, lights: &PointLights, camera_geometry: &CameraGeometry) {
+        self.shader_program.update();
         self.shader_program.activate();
         self.attribute_program.activate();
         self.attr_transform.prepare_buffer();
@@ -139,7 +154,7 @@ impl HexRenderer {
         let texture_unit = texture.activate();
         self.shader_program.set_texture(UniformKey::Texture(texture_unit), texture_unit);
 
-        let tile_frame_id = SpriteSheetFrameId::new(String::from("rock_texture.png"), NamedSpriteSheet::SpriteSheet1);
+        let tile_frame_id = NamedSprite::RockTexture.frame_id();
         let texel = textures.frame(&tile_frame_id, 0, Reverse::none());
 
         self.shader_program.set_f32(UniformKey::BevelRaise, config.bevel_height);
@@ -2,10 +2,13 @@ pub mod easing_functions;
 pub mod noise_functions;
 pub mod projections;
 pub mod rand_gen;
+pub mod rand_streams;
 pub mod rotations;
 
 pub use self::easing_functions::EasingFn;
 pub use self::noise_functions::NoiseFn;
 pub use self::projections::Projections;
 pub use self::rand_gen::RandGen;
+pub use self::rand_streams::RandStream;
+pub use self::rand_streams::RandStreams;
 pub use self::rotations::Rotations;
@@ -9,14 +9,11 @@ use rand_distr::{
     UnitCircle,
 };
 use rand_xorshift::XorShiftRng;
-use std::{
-    self,
-    time::SystemTime
-};
+use std::time::SystemTime;
 
 pub struct RandGen {
     rng: XorShiftRng,
-    _seed: [u8; 16],
+    seed: u64,
 }
 
 impl Default for RandGen {
@@ -27,24 +24,29 @@ impl Default for RandGen {
 
 impl RandGen {
     pub fn new() -> Self {
-        let seed = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-            Ok(duration) => {
-                let secs = duration.as_secs();
-                let nanos = duration.subsec_nanos();
-                [secs, u64::from(nanos)]
+        Self::from_seed(Self::time_seed())
+    }
 
-            },
-            Err(_) => {
-                [0, 0]
-            }
-        };
-        let seed: [u8; 16] = unsafe { std::mem::transmute(seed) };
+    pub fn from_seed(seed: u64) -> Self {
         RandGen {
-            rng: XorShiftRng::from_seed(seed),
-            _seed: seed
+            rng: XorShiftRng::seed_from_u64(seed),
+            seed
         }
     }
 
+    pub fn time_seed() -> u64 {
+        match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => {
+                duration.as_secs().wrapping_mul(1_000_000_000).wrapping_add(u64::from(duration.subsec_nanos()))
+            },
+            Err(_) => 0
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
     pub fn unit_circle_glm(&mut self) -> glm::Vec2 {
         let [x, y]: [f32; 2] = UnitCircle.sample(&mut self.rng);
         glm::vec2(x, y)
@@ -0,0 +1,71 @@
+use crate::math::RandGen;
+use enum_iterator::Sequence;
+
+// Independent random streams, so drawing from one never shifts the sequence of another.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Sequence)]
+pub enum RandStream {
+    Loot,
+    // Particles and other purely cosmetic effects.
+    Particles,
+    Damage,
+    // Per-bullet randomness drawn when firing.
+    Bullets,
+}
+
+impl RandStream {
+    fn index(self) -> usize {
+        match self {
+            RandStream::Loot => 0,
+            RandStream::Particles => 1,
+            RandStream::Damage => 2,
+            RandStream::Bullets => 3,
+        }
+    }
+
+    fn derive_seed(self, seed: u64) -> u64 {
+        // Spread stream indices apart with the golden ratio constant so stream seeds never collide.
+        seed ^ (self.index() as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+pub struct RandStreams {
+    seed: u64,
+    streams: Vec<RandGen>,
+}
+
+impl RandStreams {
+    pub fn from_seed(seed: u64) -> RandStreams {
+        let streams = enum_iterator::all::<RandStream>()
+            .map(|stream| RandGen::from_seed(stream.derive_seed(seed)))
+            .collect();
+
+        RandStreams {
+            seed,
+            streams,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn stream(&mut self, stream: RandStream) -> &mut RandGen {
+        &mut self.streams[stream.index()]
+    }
+
+    pub fn loot(&mut self) -> &mut RandGen {
+        self.stream(RandStream::Loot)
+    }
+
+    pub fn particles(&mut self) -> &mut RandGen {
+        self.stream(RandStream::Particles)
+    }
+
+    pub fn damage(&mut self) -> &mut RandGen {
+        self.stream(RandStream::Damage)
+    }
+
+    pub fn bullets(&mut self) -> &mut RandGen {
+        self.stream(RandStream::Bullets)
+    }
+}
@@ -1,20 +1,21 @@
 pub mod blood;
-pub mod particle_config;
 pub mod particle_event;
 pub mod particle_render_view;
+pub mod particle_renderer;
 pub mod particle_system;
 pub mod hero_switch;
 pub mod snow;
 
 pub use self::blood::BloodParticles;
-pub use self::particle_config::BloodParticleConfig;
-pub use self::particle_config::HeroSwitchParticleConfig;
-pub use self::particle_config::ParticleConfig;
-pub use self::particle_config::SnowParticleConfig;
+pub use fortress_bake::particles::BloodParticleConfig;
+pub use fortress_bake::particles::HeroSwitchParticleConfig;
+pub use fortress_bake::particles::ParticleConfig;
+pub use fortress_bake::particles::SnowParticleConfig;
 pub use self::particle_event::BloodParticleEvent;
 pub use self::particle_event::HeroSwitchParticleEvent;
 pub use self::particle_event::ParticleEvent;
 pub use self::particle_render_view::ParticleRenderView;
+pub use self::particle_renderer::ParticleRenderer;
 pub use self::particle_system::ParticleSystem;
 pub use self::hero_switch::HeroSwitchParticles;
 pub use self::snow::SnowParticles;
@@ -0,0 +1,128 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        ConfigWatcher,
+    },
+    particles::{
+        ParticleRenderView,
+        particle_render_view::{
+            BloomAttr,
+            FloatAttr,
+            Vec3Attr,
+        },
+    },
+    render::{
+        Attribute,
+        AttributeProgram,
+        CameraGeometry,
+        ShaderProgram,
+        ShaderUniformKey,
+    },
+};
+use gl::types::GLsizei;
+use std::ffi::CString;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum UniformKey {
+    ProjectionView,
+    CameraRight,
+    CameraUp,
+}
+
+impl ShaderUniformKey for UniformKey {
+    fn to_cstring(self) -> CString {
+        let s = match self {
+            UniformKey::ProjectionView => "projection_view",
+            UniformKey::CameraRight => "camera_right",
+            UniformKey::CameraUp => "camera_up",
+        };
+        CString::new(s).expect("Bad cstring")
+    }
+}
+
+pub struct ParticleRenderer {
+    shader_program: ShaderProgram<UniformKey>,
+    attribute_program: AttributeProgram,
+    attr_pos: Attribute<Vec3Attr>,
+    attr_color: Attribute<Vec3Attr>,
+    attr_bloom: Attribute<BloomAttr>,
+    attr_alpha: Attribute<FloatAttr>,
+    attr_size: Attribute<FloatAttr>,
+}
+
+impl ParticleRenderer {
+    pub fn new(config_watcher: &mut ConfigWatcher, total_particle_limit: usize) -> StatusOr<ParticleRenderer> {
+        let vertex = file::util::resource_path("shaders", "particle_vert.glsl");
+        let geometry = file::util::resource_path("shaders", "particle_geo.glsl");
+        let fragment = file::util::resource_path("shaders", "particle_frag.glsl");
+        let shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
+
+        let mut attribute_program_builder = AttributeProgram::builder();
+        let mut attr_pos = attribute_program_builder.add_attribute();
+        let mut attr_color = attribute_program_builder.add_attribute();
+        let mut attr_bloom = attribute_program_builder.add_attribute();
+        let mut attr_alpha = attribute_program_builder.add_attribute();
+        let mut attr_size = attribute_program_builder.add_attribute();
+        let attribute_program = attribute_program_builder.build();
+
+        attr_pos.data.reserve(total_particle_limit);
+        attr_color.data.reserve(total_particle_limit);
+        attr_bloom.data.reserve(total_particle_limit);
+        attr_alpha.data.reserve(total_particle_limit);
+        attr_size.data.reserve(total_particle_limit);
+
+        Ok(ParticleRenderer {
+            shader_program,
+            attribute_program,
+            attr_pos,
+            attr_color,
+            attr_bloom,
+            attr_alpha,
+            attr_size,
+        })
+    }
+
+    pub fn render_view(&mut self) -> ParticleRenderView {
+        ParticleRenderView {
+            attr_pos: &mut self.attr_pos.data,
+            attr_color: &mut self.attr_color.data,
+            attr_bloom: &mut self.attr_bloom.data,
+            attr_alpha: &mut self.attr_alpha.data,
+            attr_size: &mut self.attr_size.data,
+        }
+    }
+
+    pub fn num_queued(&self) -> usize {
+        self.attr_pos.data.len()
+    }
+
+    pub fn draw(&mut self, camera_geometry: &CameraGeometry) {
+        self.shader_program.update();
+        self.shader_program.activate();
+        self.attribute_program.activate();
+
+        self.shader_program.set_mat4(UniformKey::ProjectionView, &camera_geometry.projection_view);
+        self.shader_program.set_vec3(UniformKey::CameraRight, &camera_geometry.isometric_right);
+        self.shader_program.set_vec3(UniformKey::CameraUp, &camera_geometry.isometric_up);
+
+        self.attr_pos.prepare_buffer();
+        self.attr_color.prepare_buffer();
+        self.attr_bloom.prepare_buffer();
+        self.attr_alpha.prepare_buffer();
+        self.attr_size.prepare_buffer();
+
+        unsafe {
+            gl::DrawArraysInstanced(gl::POINTS, 0, 1, self.attr_pos.data.len() as GLsizei);
+        }
+
+        self.attr_pos.data.clear();
+        self.attr_color.data.clear();
+        self.attr_bloom.data.clear();
+        self.attr_alpha.data.clear();
+        self.attr_size.data.clear();
+
+        self.attribute_program.deactivate();
+        self.shader_program.deactivate();
+    }
+}
@@ -9,3 +9,4 @@ pub use self::collision::ProximityType;
 pub use self::matchers::ContactMatcher;
 pub use self::matchers::ProximityMatcher;
 pub use self::physics_simulation::PhysicsSimulation;
+pub use fortress_bake::physics::SimulationConfig;
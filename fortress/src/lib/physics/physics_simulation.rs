@@ -16,10 +16,14 @@ use crate::{
         Proximity,
         ProximityMatcher,
         ProximityType,
+        SimulationConfig,
     },
     world::WorldView,
 };
-use nalgebra;
+use nalgebra::{
+    self,
+    Point2,
+};
 use ncollide2d::narrow_phase::ContactEvent;
 use nphysics2d::{
     object::{
@@ -43,15 +47,13 @@ use std::{
         RefCell,
         RefMut,
     },
-    collections::HashSet,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     rc::Rc,
 };
 
-#[derive(Deserialize)]
-struct SimulationConfig {
-    force_generator_initial_capacity: usize,
-}
-
 #[derive(Clone)]
 pub struct PhysicsSimulation {
     raw: Rc<RefCell<RawPhysicsSimulation>>
@@ -86,7 +88,10 @@ pub struct RawPhysicsSimulation {
 
     registrar: EntityRegistrar,
     proximity_matchers: Vec<ProximityMatcher>,
-    contact_matchers: Vec<ContactMatcher>
+    contact_matchers: Vec<ContactMatcher>,
+
+    previous_positions: HashMap<DefaultBodyHandle, Point2<f64>>,
+    render_interpolation: Option<f64>,
 }
 
 impl RawPhysicsSimulation {
@@ -108,13 +113,16 @@ impl RawPhysicsSimulation {
             force_generators,
             registrar: EntityRegistrar::new(),
             proximity_matchers: vec!(),
-            contact_matchers: vec!()
+            contact_matchers: vec!(),
+            previous_positions: HashMap::new(),
+            render_interpolation: None,
         })
     }
 
     pub fn step(&mut self, dt: DeltaTime) {
         // Currently ignore config updates, since they aren't very useful.
         self.config.update();
+        self.snapshot_positions();
 
         self.mechanical_world.set_timestep(dt.as_f64_seconds());
         self.mechanical_world.step(
@@ -125,6 +133,33 @@ impl RawPhysicsSimulation {
             &mut self.force_generators);
     }
 
+    pub fn num_bodies(&self) -> usize {
+        self.bodies.iter().count()
+    }
+
+    pub fn set_render_interpolation(&mut self, alpha: Option<f64>) {
+        self.render_interpolation = alpha;
+    }
+
+    // Bodies created since the last step have no previous position and are drawn where they are.
+    pub fn body_position(&self, handle: DefaultBodyHandle) -> Option<Point2<f64>> {
+        let body = self.bodies.rigid_body(handle)?;
+        let current = Point2::from(body.position().translation.vector);
+        match (self.render_interpolation, self.previous_positions.get(&handle)) {
+            (Some(alpha), Some(previous)) => Some(previous + (current - previous) * alpha),
+            _ => Some(current),
+        }
+    }
+
+    fn snapshot_positions(&mut self) {
+        self.previous_positions.clear();
+        for (handle, body) in self.bodies.iter() {
+            if let Some(rigid_body) = body.downcast_ref::<RigidBody<f64>>() {
+                self.previous_positions.insert(handle, Point2::from(rigid_body.position().translation.vector));
+            }
+        }
+    }
+
     pub fn add_contact_matchers(&mut self, matchers: Vec<ContactMatcher>) {
         let mut matchers = matchers;
         self.contact_matchers.append(&mut matchers);
@@ -1,7 +0,0 @@
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
-pub enum Hero {
-    CapedWarrior,
-    FireMage,
-    Barbarian,
-    Rogue,
-}
\ No newline at end of file
@@ -1,20 +1,18 @@
-pub mod hero;
 pub mod parameters;
 pub mod player;
-pub mod player_config;
 pub mod player_id;
 pub mod player_matchers;
 pub mod player_system;
 pub mod state;
 
-pub use self::hero::Hero;
+pub use fortress_bake::players::Hero;
 pub use self::parameters::PlayerStats;
 pub use self::player::Player;
-pub use self::player_config::PlayerConfig;
-pub use self::player_config::PlayerHeroConfig;
-pub use self::player_config::PlayerBulletConfig;
-pub use self::player_config::PlayerItemConfig;
-pub use self::player_config::PlayerSystemConfig;
+pub use fortress_bake::players::PlayerConfig;
+pub use fortress_bake::players::PlayerHeroConfig;
+pub use fortress_bake::players::PlayerBulletConfig;
+pub use fortress_bake::players::PlayerItemConfig;
+pub use fortress_bake::players::PlayerSystemConfig;
 pub use self::player_id::PlayerId;
 pub use self::player_matchers::PlayerMatchers;
 pub use self::player_system::PlayerSystem;
@@ -23,6 +23,7 @@ use crate::{
         }
     },
     render::{
+        AnimationClips,
         FullyIlluminatedSpriteRenderer,
         LightDependentSpriteRenderer,
         PointLights,
@@ -53,13 +54,14 @@ impl Player {
 
     pub fn pre_update<'a>(&mut self,
                           config: &PlayerSystemConfig,
+                          clips: &AnimationClips,
                           audio: &AudioPlayer,
                           controller: IdentifiedController<'a>,
                           dt: DeltaTime,
                           particles: &mut ParticleSystem,
                           rng: &mut RandGen,
                           shake: &mut ScreenShake) {
-            if let Some(player_state_machine) = self.player_state_machine.pre_update(config, audio, controller, dt, particles, rng, shake, &mut self.player_state) {
+            if let Some(player_state_machine) = self.player_state_machine.pre_update(config, clips, audio, controller, dt, particles, rng, shake, &mut self.player_state) {
             self.player_state_machine = player_state_machine;
         }
     }
@@ -87,8 +89,8 @@ impl Player {
         self.player_state_machine.populate_lights(config, item_config, &self.player_state, lights);
     }
 
-    pub fn queue_draw(&self, config: &PlayerSystemConfig, full_light: &mut FullyIlluminatedSpriteRenderer, light_dependent: &mut LightDependentSpriteRenderer) {
-        self.player_state_machine.queue_draw(config, &self.player_state, full_light, light_dependent);
+    pub fn queue_draw(&self, config: &PlayerSystemConfig, clips: &AnimationClips, full_light: &mut FullyIlluminatedSpriteRenderer, light_dependent: &mut LightDependentSpriteRenderer) {
+        self.player_state_machine.queue_draw(config, clips, &self.player_state, full_light, light_dependent);
     }
 
     // Returns bullet direction.
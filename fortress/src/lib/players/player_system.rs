@@ -37,6 +37,7 @@ use crate::{
         self,
     },
     render::{
+        AnimationClips,
         FullyIlluminatedSpriteRenderer,
         LightDependentSpriteRenderer,
         PointLights,
@@ -53,6 +54,7 @@ use std::collections::HashMap;
 
 pub struct PlayerSystem {
     config_manager: SimpleConfigManager<PlayerSystemConfig>,
+    clips: AnimationClips,
     players: Slab<Player>,
     player_needs_controller: Vec<PlayerId>,
     player_to_controller: Vec<ControllerId>,
@@ -63,8 +65,10 @@ pub struct PlayerSystem {
 impl PlayerSystem {
     pub fn new(config_watcher: &mut ConfigWatcher, spawns: &[Point2<f64>]) -> StatusOr<PlayerSystem> {
         let config_manager = SimpleConfigManager::from_config_resource(config_watcher, "player.conf")?;
+        let clips = AnimationClips::new(config_watcher)?;
         Ok(PlayerSystem {
             config_manager,
+            clips,
             players: Slab::with_capacity(players::MAX_PLAYERS),
             player_needs_controller: Vec::with_capacity(players::MAX_PLAYERS),
             player_to_controller: Vec::with_capacity(players::MAX_PLAYERS),
@@ -89,7 +93,20 @@ impl PlayerSystem {
         if self.config_manager.update() || anyone_pressed_redeploy {
             self.redeploy(physics_sim);
         }
+        self.clips.update();
 
+        self.ingest_controller_events(audio, controller, physics_sim);
+
+        let config = self.config_manager.get();
+        for (player_key, player) in self.players.iter_mut() {
+            let controller_id = self.player_to_controller[player_key.to_raw()];
+            let identified_controller = IdentifiedController::new(controller, controller_id);
+            player.pre_update(config, &self.clips, audio, identified_controller, dt, particles, rng, shake);
+        }
+    }
+
+    // Joins and drops controllers. Also called while the simulation is paused so no connection is lost.
+    pub fn ingest_controller_events(&mut self, audio: &AudioPlayer, controller: &Controller, physics_sim: &mut PhysicsSimulation) {
         for controller_event in controller.controller_events().into_iter() {
             match controller_event {
                 ControllerEvent::KeyboardUsed => {
@@ -128,13 +145,6 @@ impl PlayerSystem {
                 }
             }
         }
-
-        let config = self.config_manager.get();
-        for (player_key, player) in self.players.iter_mut() {
-            let controller_id = self.player_to_controller[player_key.to_raw()];
-            let identified_controller = IdentifiedController::new(controller, controller_id);
-            player.pre_update(config, audio, identified_controller, dt, particles, rng, shake);
-        }
     }
 
     pub fn post_update(&mut self) {
@@ -165,7 +175,7 @@ impl PlayerSystem {
     pub fn queue_draw(&self, full_light: &mut FullyIlluminatedSpriteRenderer, light_dependent: &mut LightDependentSpriteRenderer) {
         let config = self.config_manager.get();
         for (_i, player) in self.players.iter() {
-            player.queue_draw( config, full_light, light_dependent);
+            player.queue_draw(config, &self.clips, full_light, light_dependent);
         }
     }
 
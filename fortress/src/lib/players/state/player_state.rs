@@ -36,6 +36,7 @@ use crate::{
         },
     },
     render::{
+        AnimationClips,
         FullyIlluminatedSpriteRenderer,
         PointLights,
         ScreenShake,
@@ -111,8 +112,8 @@ impl PlayerState {
         }
     }
 
-    pub fn queue_draw(&self, config: &PlayerSystemConfig, full_light: &mut FullyIlluminatedSpriteRenderer) {
-        self.weapon.queue_draw(&config.bullet, full_light);
+    pub fn queue_draw(&self, config: &PlayerSystemConfig, clips: &AnimationClips, full_light: &mut FullyIlluminatedSpriteRenderer) {
+        self.weapon.queue_draw(&config.bullet, clips, full_light);
         if let Some(position) = self.position() {
             self.collected_item_animations.queue_draw(&config.item, position, full_light);
         }
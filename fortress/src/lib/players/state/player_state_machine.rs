@@ -8,10 +8,7 @@ use crate::{
         Attack,
         OctoDirection,
         Reverse,
-        time::{
-            DeltaTime,
-            Microseconds,
-        },
+        time::DeltaTime,
         UpDownLeftRight,
     },
     items::{
@@ -27,6 +24,9 @@ use crate::{
         state::PlayerState,
     },
     render::{
+        AnimationClip,
+        AnimationClips,
+        AnimationPlayer,
         FullyIlluminatedSpriteData,
         FullyIlluminatedSpriteRenderer,
         LightDependentSpriteData,
@@ -44,17 +44,18 @@ use nalgebra::{
 };
 
 pub enum PlayerStateMachine {
-    Idle(Microseconds),
-    Walking(Microseconds),
+    Idle(AnimationPlayer),
+    Walking(AnimationPlayer),
 }
 
 impl PlayerStateMachine {
     pub fn new() -> Self {
-        Self::Idle(0)
+        Self::Idle(AnimationPlayer::new())
     }
 
     pub fn pre_update<'a>(&mut self,
                           config: &PlayerSystemConfig,
+                          clips: &AnimationClips,
                           audio: &AudioPlayer,
                           controller: IdentifiedController<'a>,
                           dt: DeltaTime,
@@ -76,17 +77,20 @@ impl PlayerStateMachine {
             player_state.try_switch_hero(&config.player, audio, particles, shake);
         }
 
+        if let Some(clip) = self.clip(config, clips, player_state) {
+            let events = self.animation_mut().update(clip, dt);
+            audio.play_clip_events(&events);
+        }
+
         match self {
-            Self::Idle(time_elapsed) => {
-                *time_elapsed += dt.as_microseconds();
+            Self::Idle(_) => {
                 if move_direction.is_some() && velocity_was_set {
-                    return Some(Self::Walking(0));
+                    return Some(Self::Walking(AnimationPlayer::new()));
                 }
             },
-            Self::Walking(time_elapsed) => {
-                *time_elapsed += dt.as_microseconds();
+            Self::Walking(_) => {
                 if move_direction.is_none() || !velocity_was_set {
-                    return Some(Self::Idle(0));
+                    return Some(Self::Idle(AnimationPlayer::new()));
                 }
             },
         }
@@ -107,11 +111,16 @@ impl PlayerStateMachine {
         player_state.populate_lights(config, item_config, lights);
     }
 
-    pub fn queue_draw(&self, config: &PlayerSystemConfig, player_state: &PlayerState, full_light: &mut FullyIlluminatedSpriteRenderer, light_dependent: &mut LightDependentSpriteRenderer) {
-        player_state.queue_draw(config, full_light);
+    pub fn queue_draw(&self,
+                      config: &PlayerSystemConfig,
+                      clips: &AnimationClips,
+                      player_state: &PlayerState,
+                      full_light: &mut FullyIlluminatedSpriteRenderer,
+                      light_dependent: &mut LightDependentSpriteRenderer) {
+        player_state.queue_draw(config, clips, full_light);
 
         if let Some(position) = player_state.position() {
-            if let Some(hero_config) = config.hero.get(&player_state.hero()) {
+            if let (Some(hero_config), Some(clip)) = (config.hero.get(&player_state.hero()), self.clip(config, clips, player_state)) {
                 let (reverse, render_offset) = if player_state.lr_dir().is_left() {
                     (Reverse::horizontally(), glm::vec2(-hero_config.render_offset.0, hero_config.render_offset.1))
                 } else {
@@ -123,20 +132,13 @@ impl PlayerStateMachine {
                               config.player.physical_radius as f32 * hero_config.render_scale.1);
                 let world_center_position = glm::vec3(position.x as f32 + render_offset.x, world_half_size.y, -(position.y as f32 + render_offset.y));
 
-                let image_name = match self {
-                    Self::Idle(_) => hero_config.idle_image_name.clone(),
-                    Self::Walking(_) => hero_config.walking_image_name.clone(),
-                };
-
-                let frame = match self {
-                    Self::Idle(time_elapsed) => (*time_elapsed / hero_config.idle_frame_duration_micros) as usize,
-                    Self::Walking(time_elapsed) => (*time_elapsed / hero_config.walking_frame_duration_micros) as usize,
-                };
+                // The extra images are drawn at the same frame as the clip.
+                let frame = self.animation().frame(clip);
 
                 light_dependent.queue(LightDependentSpriteData {
                     world_center_position,
                     world_half_size,
-                    sprite_frame_id: SpriteSheetFrameId::new(image_name, NamedSpriteSheet::Heroes),
+                    sprite_frame_id: clip.frame_id(NamedSpriteSheet::Heroes),
                     frame,
                     unit_world_rotation: Vector2::new(0.0, 0.0),
                     reverse,
@@ -179,6 +181,27 @@ impl PlayerStateMachine {
         player_state.collect_item(config, item_config, item_pickup);
     }
 
+    fn clip<'a>(&self, config: &PlayerSystemConfig, clips: &'a AnimationClips, player_state: &PlayerState) -> Option<&'a AnimationClip> {
+        let hero_config = config.hero.get(&player_state.hero())?;
+        let name = match self {
+            Self::Idle(_) => &hero_config.idle_clip,
+            Self::Walking(_) => &hero_config.walking_clip,
+        };
+        Some(clips.get(NamedSpriteSheet::Heroes, name))
+    }
+
+    fn animation(&self) -> &AnimationPlayer {
+        match self {
+            Self::Idle(animation) | Self::Walking(animation) => animation,
+        }
+    }
+
+    fn animation_mut(&mut self) -> &mut AnimationPlayer {
+        match self {
+            Self::Idle(animation) | Self::Walking(animation) => animation,
+        }
+    }
+
     fn compute_move_direction(controller: IdentifiedController) -> Option<OctoDirection> {
         let up = controller.is_pressed(ControlEvent::PlayerMove(UpDownLeftRight::Up));
         let down = controller.is_pressed(ControlEvent::PlayerMove(UpDownLeftRight::Down));
@@ -0,0 +1,153 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        ConfigWatcher,
+    },
+    profiling::{
+        FrameProfiler,
+        ProfileSection,
+    },
+    render::{
+        attribute,
+        Attribute,
+        AttributeProgram,
+        ShaderProgram,
+        ShaderUniformKey,
+    },
+};
+use gl::{
+    self,
+    types::GLsizei,
+};
+use glm;
+use std::ffi::CString;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum UniformKey {
+    ScreenWindowSize,
+}
+
+impl ShaderUniformKey for UniformKey {
+    fn to_cstring(self) -> CString {
+        let string = match self {
+            UniformKey::ScreenWindowSize => "screen_window_size",
+        };
+        CString::new(string).expect("Bad cstring")
+    }
+}
+
+// Draws the profiler's frame history as stacked bars. Goes straight to the default framebuffer, after bloom.
+pub struct FrameGraphRenderer {
+    shader_program: ShaderProgram<UniformKey>,
+    attribute_program: AttributeProgram,
+    attr_rect: Attribute<RectAttr>,
+    attr_color: Attribute<ColorAttr>,
+}
+
+impl FrameGraphRenderer {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<FrameGraphRenderer> {
+        let vertex = file::util::resource_path("shaders", "frame_graph_vert.glsl");
+        let geometry = file::util::resource_path("shaders", "frame_graph_geo.glsl");
+        let fragment = file::util::resource_path("shaders", "frame_graph_frag.glsl");
+        let shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
+
+        let mut attribute_program_builder = AttributeProgram::builder();
+        let attr_rect = attribute_program_builder.add_attribute();
+        let attr_color = attribute_program_builder.add_attribute();
+        let attribute_program = attribute_program_builder.build();
+
+        Ok(FrameGraphRenderer {
+            shader_program,
+            attribute_program,
+            attr_rect,
+            attr_color,
+        })
+    }
+
+    pub fn draw(&mut self, profiler: &FrameProfiler, screen_size: glm::IVec2) {
+        self.queue_graph(profiler, screen_size);
+
+        self.shader_program.update();
+        self.shader_program.activate();
+        self.attribute_program.activate();
+        self.shader_program.set_vec2(UniformKey::ScreenWindowSize, glm::vec2(screen_size.x as f32, screen_size.y as f32));
+
+        self.attr_rect.prepare_buffer();
+        self.attr_color.prepare_buffer();
+
+        unsafe {
+            gl::Disable(gl::DEPTH_TEST);
+            gl::DrawArraysInstanced(gl::POINTS, 0, 1, self.attr_rect.data.len() as GLsizei);
+            gl::Enable(gl::DEPTH_TEST);
+        }
+
+        self.attr_rect.data.clear();
+        self.attr_color.data.clear();
+
+        self.attribute_program.deactivate();
+        self.shader_program.deactivate();
+    }
+
+    fn queue_graph(&mut self, profiler: &FrameProfiler, screen_size: glm::IVec2) {
+        let config = profiler.config();
+        let graph = &config.graph;
+        let screen = glm::vec2(screen_size.x as f32, screen_size.y as f32);
+        let origin = glm::vec2(graph.screen_pos.0 * screen.x, graph.screen_pos.1 * screen.y);
+        let size = glm::vec2(graph.size.0 * screen.x, graph.size.1 * screen.y);
+        let micros_to_pixels = size.y / graph.full_scale_micros.max(1) as f32;
+
+        self.queue_rect(origin, size, graph.background_color);
+
+        let bar_width = size.x / config.history_len.max(1) as f32;
+        for (i, timings) in profiler.history().enumerate() {
+            let mut bottom = origin.y;
+            let left = origin.x + i as f32 * bar_width;
+            let top_level_sections = enum_iterator::all::<ProfileSection>().filter(|section| section.parent().is_none());
+            for section in top_level_sections {
+                let top = (bottom + timings.section_micros(section) as f32 * micros_to_pixels).min(origin.y + size.y);
+                if top > bottom {
+                    let color = config.section_colors.get(&section).copied().unwrap_or((1.0, 1.0, 1.0));
+                    self.queue_rect(glm::vec2(left, bottom), glm::vec2(bar_width, top - bottom), (color.0, color.1, color.2, 1.0));
+                }
+                bottom = top;
+            }
+        }
+
+        let budget_y = origin.y + (graph.budget_micros as f32 * micros_to_pixels).min(size.y);
+        self.queue_rect(glm::vec2(origin.x, budget_y), glm::vec2(size.x, 1.0), graph.budget_line_color);
+    }
+
+    fn queue_rect(&mut self, bottom_left: glm::Vec2, size: glm::Vec2, color: (f32, f32, f32, f32)) {
+        self.attr_rect.data.push(RectAttr {
+            bottom_left,
+            size,
+        });
+        self.attr_color.data.push(ColorAttr {
+            rgba: glm::vec4(color.0, color.1, color.2, color.3),
+        });
+    }
+}
+
+#[repr(C)]
+struct RectAttr {
+    bottom_left: glm::Vec2,
+    size: glm::Vec2,
+}
+
+impl attribute::KnownComponent for RectAttr {
+    fn component() -> (attribute::NumComponents, attribute::ComponentType) {
+        (attribute::NumComponents::S4, attribute::ComponentType::Float)
+    }
+}
+
+#[repr(C)]
+struct ColorAttr {
+    rgba: glm::Vec4,
+}
+
+impl attribute::KnownComponent for ColorAttr {
+    fn component() -> (attribute::NumComponents, attribute::ComponentType) {
+        (attribute::NumComponents::S4, attribute::ComponentType::Float)
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    app::StatusOr,
+    dimensions::time::Microseconds,
+    file::{
+        ConfigWatcher,
+        SimpleConfigManager,
+    },
+    profiling::{
+        FrameTimings,
+        ProfileCounter,
+        ProfileSection,
+        ProfilerConfig,
+    },
+    text::{
+        NamedText,
+        RasterSize,
+        ScreenTextRequest,
+        TextContent,
+        TextRenderer,
+    },
+};
+use glm;
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::PathBuf,
+    time::Instant,
+};
+
+// Rows are flushed this often so a crash loses at most this many frames.
+const CSV_FLUSH_INTERVAL_FRAMES: u64 = 60;
+
+// Returned by FrameProfiler::start and handed back to FrameProfiler::stop.
+pub struct ProfileScope {
+    section: ProfileSection,
+    start: Instant,
+}
+
+pub struct FrameProfiler {
+    config: SimpleConfigManager<ProfilerConfig>,
+    current: FrameTimings,
+    frame_start: Instant,
+    frame_index: u64,
+    history: VecDeque<FrameTimings>,
+    show_graph: bool,
+    csv: Option<BufWriter<File>>,
+}
+
+impl FrameProfiler {
+    pub fn new(config_watcher: &mut ConfigWatcher, csv_path: Option<&PathBuf>) -> StatusOr<FrameProfiler> {
+        let config: SimpleConfigManager<ProfilerConfig> = SimpleConfigManager::from_config_resource(config_watcher, "profiler.conf")?;
+        let csv = match csv_path {
+            Some(path) => Some(Self::create_csv(path)?),
+            None => None,
+        };
+        let (history_len, show_graph) = {
+            let config = config.get();
+            (config.history_len, config.show_graph_on_start)
+        };
+
+        Ok(FrameProfiler {
+            config,
+            current: FrameTimings::default(),
+            frame_start: Instant::now(),
+            frame_index: 0,
+            history: VecDeque::with_capacity(history_len),
+            show_graph,
+            csv,
+        })
+    }
+
+    pub fn begin_frame(&mut self) {
+        self.config.update();
+        self.current = FrameTimings::default();
+        self.frame_start = Instant::now();
+    }
+
+    pub fn end_frame(&mut self) {
+        self.current.total_micros = Self::micros_since(self.frame_start);
+
+        if let Some(ref mut csv) = self.csv {
+            let flush = (self.frame_index + 1) % CSV_FLUSH_INTERVAL_FRAMES == 0;
+            if let Err(e) = Self::write_csv_row(csv, self.frame_index, &self.current, flush) {
+                println!("Stopped writing profiler CSV: {}", e);
+                self.csv = None;
+            }
+        }
+
+        let history_len = self.config.get().history_len;
+        while self.history.len() >= history_len.max(1) {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.current.clone());
+        self.frame_index += 1;
+    }
+
+    pub fn start(&self, section: ProfileSection) -> ProfileScope {
+        ProfileScope {
+            section,
+            start: Instant::now(),
+        }
+    }
+
+    // Sections stopped more than once in a frame accumulate.
+    pub fn stop(&mut self, scope: ProfileScope) {
+        self.current.add_section_micros(scope.section, Self::micros_since(scope.start));
+    }
+
+    pub fn add_count(&mut self, counter: ProfileCounter, count: usize) {
+        self.current.add_count(counter, count);
+    }
+
+    pub fn set_count(&mut self, counter: ProfileCounter, count: usize) {
+        self.current.set_count(counter, count);
+    }
+
+    pub fn toggle_graph(&mut self) {
+        self.show_graph = !self.show_graph;
+    }
+
+    pub fn show_graph(&self) -> bool {
+        self.show_graph
+    }
+
+    pub fn config(&self) -> &ProfilerConfig {
+        self.config.get()
+    }
+
+    pub fn history(&self) -> impl Iterator<Item = &FrameTimings> {
+        self.history.iter()
+    }
+
+    pub fn average_micros(&self, section: ProfileSection) -> Microseconds {
+        if self.history.is_empty() {
+            return 0;
+        }
+        let sum: Microseconds = self.history.iter().map(|timings| timings.section_micros(section)).sum();
+        sum / self.history.len() as Microseconds
+    }
+
+    pub fn average_total_micros(&self) -> Microseconds {
+        if self.history.is_empty() {
+            return 0;
+        }
+        let sum: Microseconds = self.history.iter().map(|timings| timings.total_micros).sum();
+        sum / self.history.len() as Microseconds
+    }
+
+    pub fn queue_legend(&self, text: &mut TextRenderer) {
+        let config = &self.config.get().legend;
+        let mut line = 0;
+        let mut queue_line = |text: &mut TextRenderer, label: NamedText, value: i64, depth: usize, color: (f32, f32, f32)| {
+            let screen_position_percentage = glm::vec3(
+                config.screen_pos.0 + depth as f32 * config.indent,
+                config.screen_pos.1 - line as f32 * config.line_height,
+                config.screen_pos.2);
+            text.queue_screen_text(
+                [TextContent::Text(label), TextContent::Number(value)].iter().copied(),
+                ScreenTextRequest {
+                    screen_position_percentage,
+                    raster_size: RasterSize::Small,
+                    color: glm::vec3(color.0, color.1, color.2),
+                    alpha: config.alpha,
+                });
+            line += 1;
+        };
+
+        queue_line(text, NamedText::ProfileFrame, self.average_total_micros(), 0, config.color);
+        for section in enum_iterator::all::<ProfileSection>() {
+            let color = self.config.get().section_colors.get(&section).copied().unwrap_or(config.color);
+            queue_line(text, section.label(), self.average_micros(section), section.depth() + 1, color);
+        }
+        if let Some(last) = self.history.back() {
+            for counter in enum_iterator::all::<ProfileCounter>() {
+                queue_line(text, counter.label(), last.counter(counter) as i64, 0, config.color);
+            }
+        }
+    }
+
+    fn micros_since(start: Instant) -> Microseconds {
+        start.elapsed().as_micros() as Microseconds
+    }
+
+    fn create_csv(path: &PathBuf) -> StatusOr<BufWriter<File>> {
+        let file = File::create(path)
+            .map_err(|e| format!("Couldn't create profiler CSV {:?}: {}", path, e))?;
+        let mut csv = BufWriter::new(file);
+
+        let mut header = vec!(String::from("frame"), String::from("total_us"));
+        header.extend(enum_iterator::all::<ProfileSection>().map(|section| format!("{:?}_us", section)));
+        header.extend(enum_iterator::all::<ProfileCounter>().map(|counter| format!("{:?}", counter)));
+        writeln!(csv, "{}", header.join(","))
+            .map_err(|e| format!("Couldn't write profiler CSV {:?}: {}", path, e))?;
+        println!("Writing frame timings to {:?}", path);
+        Ok(csv)
+    }
+
+    fn write_csv_row(csv: &mut BufWriter<File>, frame_index: u64, timings: &FrameTimings, flush: bool) -> StatusOr<()> {
+        let mut row = vec!(frame_index.to_string(), timings.total_micros.to_string());
+        row.extend(enum_iterator::all::<ProfileSection>().map(|section| timings.section_micros(section).to_string()));
+        row.extend(enum_iterator::all::<ProfileCounter>().map(|counter| timings.counter(counter).to_string()));
+        writeln!(csv, "{}", row.join(","))
+            .map_err(|e| format!("{}", e))?;
+        if flush {
+            csv.flush().map_err(|e| format!("{}", e))?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for FrameProfiler {
+    // BufWriter also flushes on drop, but would swallow the error.
+    fn drop(&mut self) {
+        if let Some(ref mut csv) = self.csv {
+            if let Err(e) = csv.flush() {
+                println!("Couldn't flush profiler CSV: {}", e);
+            }
+        }
+    }
+}
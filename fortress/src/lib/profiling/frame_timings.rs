@@ -0,0 +1,46 @@
+use crate::{
+    dimensions::time::Microseconds,
+    profiling::{
+        ProfileCounter,
+        ProfileSection,
+    },
+};
+
+#[derive(Clone)]
+pub struct FrameTimings {
+    pub total_micros: Microseconds,
+    section_micros: Vec<Microseconds>,
+    counters: Vec<usize>,
+}
+
+impl Default for FrameTimings {
+    fn default() -> Self {
+        FrameTimings {
+            total_micros: 0,
+            section_micros: vec!(0; enum_iterator::cardinality::<ProfileSection>()),
+            counters: vec!(0; enum_iterator::cardinality::<ProfileCounter>()),
+        }
+    }
+}
+
+impl FrameTimings {
+    pub fn section_micros(&self, section: ProfileSection) -> Microseconds {
+        self.section_micros[section.index()]
+    }
+
+    pub fn counter(&self, counter: ProfileCounter) -> usize {
+        self.counters[counter.index()]
+    }
+
+    pub fn add_section_micros(&mut self, section: ProfileSection, micros: Microseconds) {
+        self.section_micros[section.index()] += micros;
+    }
+
+    pub fn add_count(&mut self, counter: ProfileCounter, count: usize) {
+        self.counters[counter.index()] += count;
+    }
+
+    pub fn set_count(&mut self, counter: ProfileCounter, count: usize) {
+        self.counters[counter.index()] = count;
+    }
+}
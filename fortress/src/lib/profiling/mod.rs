@@ -0,0 +1,14 @@
+pub mod frame_graph_renderer;
+pub mod frame_profiler;
+pub mod frame_timings;
+pub mod profile_counter;
+
+pub use self::frame_graph_renderer::FrameGraphRenderer;
+pub use self::frame_profiler::FrameProfiler;
+pub use self::frame_profiler::ProfileScope;
+pub use self::frame_timings::FrameTimings;
+pub use self::profile_counter::ProfileCounter;
+pub use fortress_bake::profiling::ProfileSection;
+pub use fortress_bake::profiling::FrameGraphConfig;
+pub use fortress_bake::profiling::ProfilerConfig;
+pub use fortress_bake::profiling::ProfilerLegendConfig;
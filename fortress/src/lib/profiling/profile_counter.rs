@@ -0,0 +1,28 @@
+use crate::text::NamedText;
+use enum_iterator::Sequence;
+
+// Per-frame tallies recorded alongside section timings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Sequence)]
+pub enum ProfileCounter {
+    SimulationSteps,
+    PhysicsBodies,
+    SpritesQueued,
+    Lights,
+    Particles,
+}
+
+impl ProfileCounter {
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn label(self) -> NamedText {
+        match self {
+            ProfileCounter::SimulationSteps => NamedText::CounterSimulationSteps,
+            ProfileCounter::PhysicsBodies => NamedText::CounterPhysicsBodies,
+            ProfileCounter::SpritesQueued => NamedText::CounterSpritesQueued,
+            ProfileCounter::Lights => NamedText::CounterLights,
+            ProfileCounter::Particles => NamedText::CounterParticles,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
     app::StatusOr,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
@@ -43,13 +46,12 @@ pub struct BloomCompositorShader {
 }
 
 impl BloomCompositorShader {
-    pub fn new() -> StatusOr<Self> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
         let vertex = file::util::resource_path("shaders", "bloom_compositor_vert.glsl");
         let fragment = file::util::resource_path("shaders", "bloom_compositor_frag.glsl");
-        let mut shader_program = ShaderProgram::from_short_pipeline(&vertex, &fragment)?;
+        let mut shader_program = ShaderProgram::from_short_pipeline(config_watcher, &vertex, &fragment)?;
         shader_program.activate();
-        shader_program.set_texture(UniformKey::Scene, TextureUnit::Texture0);
-        shader_program.set_texture(UniformKey::Bloom, TextureUnit::Texture1);
+        Self::set_fixed_uniforms(&mut shader_program);
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let mut attr_position = attribute_program_builder.add_attribute_with_advance(AttributeAdvance::PerVertex);
@@ -78,7 +80,11 @@ impl BloomCompositorShader {
     }
 
     pub fn draw(&mut self, config: &BloomConfig, scene: &FrameBufferTexture, bloom: &BloomPingPongBuffer) {
+        let reloaded = self.shader_program.update();
         self.shader_program.activate();
+        if reloaded {
+            Self::set_fixed_uniforms(&mut self.shader_program);
+        }
         self.shader_program.set_f32(UniformKey::BloomIntensityMultiplier, config.bloom_intensity_multiplier);
 
         TextureUnit::Texture0.activate();
@@ -94,6 +100,11 @@ impl BloomCompositorShader {
         }
         self.attribute_program.deactivate();
     }
+
+    fn set_fixed_uniforms(shader_program: &mut ShaderProgram<UniformKey>) {
+        shader_program.set_texture(UniformKey::Scene, TextureUnit::Texture0);
+        shader_program.set_texture(UniformKey::Bloom, TextureUnit::Texture1);
+    }
 }
 
 #[repr(C)]
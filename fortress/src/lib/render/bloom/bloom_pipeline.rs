@@ -1,5 +1,6 @@
 use crate::{
     app::StatusOr,
+    file::ConfigWatcher,
     render::{
         BloomConfig,
         BloomCompositorShader,
@@ -17,9 +18,8 @@ use gl::{
 };
 use glm;
 
-pub struct BloomPipeline {
-    blur_shader: BlurShader,
-    compositor_shader: BloomCompositorShader,
+// Everything sized to the drawable. Rebuilt wholesale on resize.
+struct BloomRenderTargets {
     scene_buffer: FrameBuffer,
     color_texture: FrameBufferTexture,
     bloom_texture: FrameBufferTexture,
@@ -28,11 +28,8 @@ pub struct BloomPipeline {
     _depth_render_buffer: DepthRenderBuffer,
 }
 
-impl BloomPipeline {
-    pub fn new(screen_size: glm::IVec2) -> StatusOr<Self> {
-        let blur_shader = BlurShader::new()?;
-        let compositor_shader = BloomCompositorShader::new()?;
-
+impl BloomRenderTargets {
+    fn new(screen_size: glm::IVec2) -> StatusOr<Self> {
         let scene_buffer = FrameBuffer::new();
         scene_buffer.bind();
 
@@ -50,9 +47,7 @@ impl BloomPipeline {
         let ping_pong0 = BloomPingPongBuffer::new(screen_size)?;
         let ping_pong1 = BloomPingPongBuffer::new(screen_size)?;
 
-        Ok(BloomPipeline {
-            blur_shader,
-            compositor_shader,
+        Ok(BloomRenderTargets {
             scene_buffer,
             color_texture,
             bloom_texture,
@@ -61,14 +56,46 @@ impl BloomPipeline {
             _depth_render_buffer: depth_render_buffer,
         })
     }
+}
+
+pub struct BloomPipeline {
+    blur_shader: BlurShader,
+    compositor_shader: BloomCompositorShader,
+    targets: BloomRenderTargets,
+    screen_size: glm::IVec2,
+}
+
+impl BloomPipeline {
+    pub fn new(config_watcher: &mut ConfigWatcher, screen_size: glm::IVec2) -> StatusOr<Self> {
+        let blur_shader = BlurShader::new(config_watcher)?;
+        let compositor_shader = BloomCompositorShader::new(config_watcher)?;
+        let targets = BloomRenderTargets::new(screen_size)?;
+
+        Ok(BloomPipeline {
+            blur_shader,
+            compositor_shader,
+            targets,
+            screen_size,
+        })
+    }
+
+    // Screen size is the drawable size in pixels, not the window size.
+    pub fn resize(&mut self, screen_size: glm::IVec2) -> StatusOr<()> {
+        if screen_size == self.screen_size || screen_size.x <= 0 || screen_size.y <= 0 {
+            return Ok(());
+        }
+        self.targets = BloomRenderTargets::new(screen_size)?;
+        self.screen_size = screen_size;
+        Ok(())
+    }
 
     pub fn begin(&self) {
-        self.scene_buffer.bind();
+        self.targets.scene_buffer.bind();
         Self::clear();
     }
 
     pub fn blur(&mut self, config: &BloomConfig) {
-        self.scene_buffer.unbind();
+        self.targets.scene_buffer.unbind();
         self.blur_pass(config);
         self.draw_to_default_framebuffer(config);
     }
@@ -76,29 +103,30 @@ impl BloomPipeline {
     fn blur_pass(&mut self, config: &BloomConfig) {
         self.blur_shader.activate();
 
+        let targets = &self.targets;
         let num_iterations = 2 * config.num_passes;
         for i in 0..num_iterations {
             let horizontal = i % 2 == 0;
             if horizontal {
-                self.ping_pong1.bind_frame_buffer();
+                targets.ping_pong1.bind_frame_buffer();
             } else {
-                self.ping_pong0.bind_frame_buffer();
+                targets.ping_pong0.bind_frame_buffer();
             }
             self.blur_shader.set_horizontal_mode(horizontal);
             TextureUnit::Texture0.activate();
             match horizontal {
-                _ if i == 0 => self.bloom_texture.bind(),
-                true => self.ping_pong0.bind_color_texture(),
-                false => self.ping_pong1.bind_color_texture()
+                _ if i == 0 => targets.bloom_texture.bind(),
+                true => targets.ping_pong0.bind_color_texture(),
+                false => targets.ping_pong1.bind_color_texture()
             };
             self.blur_shader.draw();
         }
     }
 
     fn draw_to_default_framebuffer(&mut self, config: &BloomConfig) {
-        self.scene_buffer.unbind();
+        self.targets.scene_buffer.unbind();
         Self::clear();
-        self.compositor_shader.draw(config, &self.color_texture, &self.ping_pong0);
+        self.compositor_shader.draw(config, &self.targets.color_texture, &self.targets.ping_pong0);
     }
 
     fn clear() {
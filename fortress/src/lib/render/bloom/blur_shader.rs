@@ -1,6 +1,9 @@
 use crate::{
     app::StatusOr,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
@@ -38,12 +41,12 @@ pub struct BlurShader {
 }
 
 impl BlurShader {
-    pub fn new() -> StatusOr<Self> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
         let vertex = file::util::resource_path("shaders", "blur_vert.glsl");
         let fragment = file::util::resource_path("shaders", "blur_frag.glsl");
-        let mut shader_program = ShaderProgram::from_short_pipeline(&vertex, &fragment)?;
+        let mut shader_program = ShaderProgram::from_short_pipeline(config_watcher, &vertex, &fragment)?;
         shader_program.activate();
-        shader_program.set_texture(UniformKey::ImageTexture, TextureUnit::Texture0);
+        Self::set_fixed_uniforms(&mut shader_program);
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let mut attr_position = attribute_program_builder.add_attribute_with_advance(AttributeAdvance::PerVertex);
@@ -71,8 +74,12 @@ impl BlurShader {
         })
     }
 
-    pub fn activate(&self) {
+    pub fn activate(&mut self) {
+        let reloaded = self.shader_program.update();
         self.shader_program.activate();
+        if reloaded {
+            Self::set_fixed_uniforms(&mut self.shader_program);
+        }
     }
 
     pub fn set_horizontal_mode(&mut self, horizontal: bool) {
@@ -88,6 +95,10 @@ impl BlurShader {
         }
         self.attribute_program.deactivate();
     }
+
+    fn set_fixed_uniforms(shader_program: &mut ShaderProgram<UniformKey>) {
+        shader_program.set_texture(UniformKey::ImageTexture, TextureUnit::Texture0);
+    }
 }
 
 #[repr(C)]
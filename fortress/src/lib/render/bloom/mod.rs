@@ -1,4 +1,3 @@
-pub mod bloom_config;
 pub mod bloom_compositor_shader;
 pub mod bloom_pipeline;
 pub mod bloom_ping_pong_buffer;
@@ -7,7 +6,7 @@ pub mod frame_buffer;
 pub mod frame_buffer_texture;
 pub mod depth_render_buffer;
 
-pub use self::bloom_config::BloomConfig;
+pub use fortress_bake::render::BloomConfig;
 pub use self::bloom_compositor_shader::BloomCompositorShader;
 pub use self::bloom_pipeline::BloomPipeline;
 pub use self::bloom_ping_pong_buffer::BloomPingPongBuffer;
@@ -25,6 +25,7 @@ use nalgebra::{
 pub struct Camera {
     config_manager: SimpleConfigManager<CameraConfig>,
     world_position: Point3<f64>,
+    previous_world_position: Point3<f64>,
     screen_shake: ScreenShake,
 }
 
@@ -44,11 +45,12 @@ impl Camera {
         Ok(Camera {
             config_manager,
             world_position,
+            previous_world_position: world_position,
             screen_shake: ScreenShake::new(),
         })
     }
 
-    pub fn geometry(&self, screen_size: glm::IVec2) -> CameraGeometry {
+    pub fn geometry(&self, screen_size: glm::IVec2, interpolation_alpha: f64) -> CameraGeometry {
         let config = self.config_manager.get();
 
         let isometric = CameraAngles::new(
@@ -57,10 +59,11 @@ impl Camera {
         let isometric_view = glm::ext::look_at(glm::vec3(0.0, 0.0, 0.0), isometric.lookat(), isometric.up());
 
 
+        let interpolated_position = self.previous_world_position + (self.world_position - self.previous_world_position) * interpolation_alpha;
         let world_position =
-            glm::vec3(self.world_position.x as f32 + config.camera_pos_offset.0,
-                      self.world_position.y as f32,
-                      -self.world_position.z as f32 + config.camera_pos_offset.1);
+            glm::vec3(interpolated_position.x as f32 + config.camera_pos_offset.0,
+                      interpolated_position.y as f32,
+                      -interpolated_position.z as f32 + config.camera_pos_offset.1);
 
         let shook = self.screen_shake.shake_rotation(&config.screen_shake, &isometric);
         let projection_view = Self::projection(config, screen_size) * glm::ext::look_at(world_position, world_position + shook.lookat(), shook.up());
@@ -81,6 +84,7 @@ impl Camera {
     }
 
     pub fn post_update(&mut self, player_locs: Vec<Point2<f64>>, dt: DeltaTime) {
+        self.previous_world_position = self.world_position;
         if player_locs.is_empty() {
             return;
         }
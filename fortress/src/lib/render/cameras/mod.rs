@@ -1,12 +1,11 @@
 pub mod camera;
 pub mod camera_geometry;
-pub mod camera_config;
 pub mod camera_stream_info;
 pub mod screen_shake;
 
 pub use self::camera::Camera;
-pub use self::camera_config::CameraConfig;
-pub use self::camera_config::ScreenShakeConfig;
+pub use fortress_bake::render::CameraConfig;
+pub use fortress_bake::render::ScreenShakeConfig;
 pub use self::camera_geometry::CameraAngles;
 pub use self::camera_geometry::CameraGeometry;
 pub use self::camera_stream_info::CameraStreamBounds;
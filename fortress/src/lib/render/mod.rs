@@ -9,16 +9,23 @@ pub mod textures;
 pub mod viewport;
 
 pub use fortress_bake::render::AllPackedSpriteSheets;
+pub use fortress_bake::render::AnimationClip;
+pub use fortress_bake::render::AsepriteSheet;
 pub use fortress_bake::render::attribute;
 pub use fortress_bake::render::Attribute;
 pub use fortress_bake::render::AttributeAdvance;
 pub use fortress_bake::render::AttributeProgram;
 pub use fortress_bake::render::AttributeProgramBuilder;
+pub use fortress_bake::render::BackgroundRendererConfig;
 pub use fortress_bake::render::BakedSpriteSheetConfig;
 pub use fortress_bake::render::FramesInfo;
+pub use fortress_bake::render::LoopMode;
+pub use fortress_bake::render::NamedSprite;
 pub use fortress_bake::render::NamedSpriteSheet;
 pub use fortress_bake::render::PackedSpriteSheet;
+pub use fortress_bake::render::PointLightsConfig;
 pub use fortress_bake::render::Png;
+pub use fortress_bake::render::QuadBounds;
 pub use fortress_bake::render::SerializableBitmap;
 pub use fortress_bake::render::SpriteSheetConfig;
 pub use fortress_bake::render::SpriteSheetFrameId;
@@ -48,6 +55,8 @@ pub use self::renderer::LightDependentSpriteData;
 pub use self::renderer::LightDependentSpriteRenderer;
 pub use self::shader::ShaderProgram;
 pub use self::shader::ShaderUniformKey;
+pub use self::sprites::AnimationClips;
+pub use self::sprites::AnimationPlayer;
 pub use self::sprites::SpriteSheetTextureManager;
 pub use self::textures::BitmapTexture;
 pub use self::textures::PngTexture;
@@ -1,10 +1,13 @@
 use crate::{
     app::StatusOr,
     file::{
-        self,
-        Config,
+        ConfigWatcher,
+        SimpleConfigManager,
+    },
+    render::{
+        CameraStreamInfo,
+        PointLightsConfig,
     },
-    render::CameraStreamInfo,
 };
 use nalgebra::Point2;
 
@@ -38,29 +41,37 @@ impl PointLight {
     }
 }
 
-#[derive(Deserialize)]
-struct PointLightsConfig {
-   initial_capacity: usize
-}
-
 pub struct PointLights {
+    config: SimpleConfigManager<PointLightsConfig>,
     max_num_lights: usize,
     lights: Vec<PointLight>,
     camera_stream_info: Option<CameraStreamInfo>,
+    // So a crowded map reports dropped lights once rather than every frame.
+    reported_dropped: bool,
 }
 
 impl PointLights {
-    pub fn new() -> StatusOr<Self> {
-        let config_path = file::util::resource_path("config", "lights.conf");
-        let config = PointLightsConfig::from_path(&config_path)?;
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
+        let config = SimpleConfigManager::<PointLightsConfig>::from_config_resource(config_watcher, "lights.conf")?;
+        let initial_capacity = config.get().initial_capacity;
 
         Ok(PointLights {
-            max_num_lights: config.initial_capacity,
-            lights: Vec::with_capacity(config.initial_capacity),
+            config,
+            max_num_lights: initial_capacity,
+            lights: Vec::with_capacity(initial_capacity),
             camera_stream_info: None,
+            reported_dropped: false,
         })
     }
 
+    pub fn update(&mut self) {
+        if self.config.update() {
+            self.max_num_lights = self.config.get().initial_capacity;
+            self.lights.reserve(self.max_num_lights.saturating_sub(self.lights.len()));
+            self.reported_dropped = false;
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.lights.len()
     }
@@ -91,7 +102,12 @@ impl PointLights {
         self.lights.append(&mut lights);
 
         if self.lights.len() > self.max_num_lights {
-            panic!("Need to update shaders to support more than {} lights", self.lights.len());
+            if !self.reported_dropped {
+                println!("Dropping {} of {} lights, over the initial_capacity of {} in lights.conf",
+                         self.lights.len() - self.max_num_lights, self.lights.len(), self.max_num_lights);
+                self.reported_dropped = true;
+            }
+            self.lights.truncate(self.max_num_lights);
         }
     }
 }
@@ -10,6 +10,7 @@ use crate::{
         Attribute,
         AttributeAdvance,
         AttributeProgram,
+        BackgroundRendererConfig,
         CameraGeometry,
         NamedSpriteSheet,
         ShaderProgram,
@@ -21,13 +22,6 @@ use crate::{
 };
 use std::ffi::CString;
 
-#[derive(Deserialize)]
-struct BackgroundRendererConfig {
-    pub camera_speed: f32,
-    // Screen pixels / pixels sampled.
-    pub zoom: f32
-}
-
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 enum UniformKey {
     Texture(TextureUnit)
@@ -57,7 +51,7 @@ impl BackgroundRenderer {
 
         let vertex = file::util::resource_path("shaders", "background_vert.glsl");
         let fragment = file::util::resource_path("shaders", "background_frag.glsl");
-        let shader_program = ShaderProgram::from_short_pipeline(&vertex, &fragment)?;
+        let shader_program = ShaderProgram::from_short_pipeline(config_watcher, &vertex, &fragment)?;
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let mut attr_vertex = attribute_program_builder.add_attribute_with_advance(AttributeAdvance::PerVertex);
@@ -91,6 +85,7 @@ impl BackgroundRenderer {
         let texture = textures.texture(NamedSpriteSheet::GalaxyGround);
         self.set_texels(texture, camera_geometry.world_position);
 
+        self.shader_program.update();
         self.shader_program.activate();
         self.attribute_program.activate();
         self.attr_vertex.prepare_buffer();
@@ -1,13 +1,17 @@
 use crate::{
     app::StatusOr,
     dimensions::Reverse,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
         AttributeProgram,
         CameraGeometry,
         NamedSpriteSheet,
+        QuadBounds,
         SpriteSheetFrameId,
         SpriteSheetTextureManager,
         ShaderProgram,
@@ -67,16 +71,17 @@ pub struct FullyIlluminatedSpriteRenderer {
     attr_size: Attribute<SpriteSizeAttr>,
     attr_texel: Attribute<Texel>,
     attr_rot: Attribute<RotationAttr>,
+    attr_quad_bounds: Attribute<QuadBounds>,
     attr_bloom_intensity: Attribute<BloomIntensityAttr>,
     per_pack_attrs: HashMap<NamedSpriteSheet, Vec<FullyIlluminatedSpriteData>>,
 }
 
 impl FullyIlluminatedSpriteRenderer {
-    pub fn new() -> StatusOr<FullyIlluminatedSpriteRenderer> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<FullyIlluminatedSpriteRenderer> {
         let vertex = file::util::resource_path("shaders", "full_light_sprite_vert.glsl");
         let geometry = file::util::resource_path("shaders", "full_light_sprite_geo.glsl");
         let fragment = file::util::resource_path("shaders", "full_light_sprite_frag.glsl");
-        let shader_program = ShaderProgram::from_long_pipeline(&vertex, &geometry, &fragment)?;
+        let shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let attr_pos = attribute_program_builder.add_attribute();
@@ -84,6 +89,7 @@ impl FullyIlluminatedSpriteRenderer {
         let attr_texel = attribute_program_builder.add_attribute();
         let attr_rot = attribute_program_builder.add_attribute();
         let attr_bloom_intensity = attribute_program_builder.add_attribute();
+        let attr_quad_bounds = attribute_program_builder.add_attribute();
         let attribute_program = attribute_program_builder.build();
 
         Ok(FullyIlluminatedSpriteRenderer {
@@ -93,6 +99,7 @@ impl FullyIlluminatedSpriteRenderer {
             attr_size,
             attr_texel,
             attr_rot,
+            attr_quad_bounds,
             attr_bloom_intensity,
             per_pack_attrs: HashMap::new(),
         })
@@ -108,7 +115,12 @@ impl FullyIlluminatedSpriteRenderer {
         }
     }
 
+    pub fn num_queued(&self) -> usize {
+        self.per_pack_attrs.values().map(Vec::len).sum()
+    }
+
     pub fn draw(&mut self, textures: &SpriteSheetTextureManager, camera_geometry: &CameraGeometry) {
+        self.shader_program.update();
         self.shader_program.activate();
         self.attribute_program.activate();
 
@@ -124,6 +136,7 @@ impl FullyIlluminatedSpriteRenderer {
 
             for datum in queued_draw.iter() {
                 let texel = textures.frame(&datum.sprite_frame_id, datum.frame, datum.reverse);
+                let quad_bounds = textures.quad_bounds(&datum.sprite_frame_id, datum.reverse);
 
                 self.attr_pos.data.push(SpritePositionAttr {
                     world_center_position: datum.world_center_position,
@@ -135,6 +148,7 @@ impl FullyIlluminatedSpriteRenderer {
                 self.attr_rot.data.push(RotationAttr {
                     unit_world_rotation_xz: glm::vec2(datum.unit_world_rotation.x as f32, -datum.unit_world_rotation.y as f32)
                 });
+                self.attr_quad_bounds.data.push(quad_bounds);
                 self.attr_bloom_intensity.data.push(BloomIntensityAttr {
                     intensity: datum.bloom_intensity,
                 })
@@ -144,6 +158,7 @@ impl FullyIlluminatedSpriteRenderer {
             self.attr_size.prepare_buffer();
             self.attr_texel.prepare_buffer();
             self.attr_rot.prepare_buffer();
+            self.attr_quad_bounds.prepare_buffer();
             self.attr_bloom_intensity.prepare_buffer();
 
             unsafe {
@@ -154,6 +169,7 @@ impl FullyIlluminatedSpriteRenderer {
             self.attr_size.data.clear();
             self.attr_texel.data.clear();
             self.attr_rot.data.clear();
+            self.attr_quad_bounds.data.clear();
             self.attr_bloom_intensity.data.clear();
         }
 
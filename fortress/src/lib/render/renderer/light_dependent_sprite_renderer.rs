@@ -1,7 +1,10 @@
 use crate::{
     app::StatusOr,
     dimensions::Reverse,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
@@ -9,6 +12,7 @@ use crate::{
         CameraGeometry,
         CameraStreamInfo,
         NamedSpriteSheet,
+        QuadBounds,
         SpriteSheetFrameId,
         SpriteSheetTextureManager,
         PointLights,
@@ -87,22 +91,24 @@ pub struct LightDependentSpriteRenderer {
     attr_size: Attribute<SpriteSizeAttr>,
     attr_texel: Attribute<Texel>,
     attr_rot: Attribute<RotationAttr>,
+    attr_quad_bounds: Attribute<QuadBounds>,
     per_pack_attrs: HashMap<NamedSpriteSheet, Vec<LightDependentSpriteData>>,
     camera_stream_info: Option<CameraStreamInfo>,
 }
 
 impl LightDependentSpriteRenderer {
-    pub fn new() -> StatusOr<LightDependentSpriteRenderer> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<LightDependentSpriteRenderer> {
         let vertex = file::util::resource_path("shaders", "light_dependent_sprite_vert.glsl");
         let geometry = file::util::resource_path("shaders", "light_dependent_sprite_geo.glsl");
         let fragment = file::util::resource_path("shaders", "light_dependent_sprite_frag.glsl");
-        let shader_program = ShaderProgram::from_long_pipeline(&vertex, &geometry, &fragment)?;
+        let shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let attr_pos = attribute_program_builder.add_attribute();
         let attr_size = attribute_program_builder.add_attribute();
         let attr_texel = attribute_program_builder.add_attribute();
         let attr_rot = attribute_program_builder.add_attribute();
+        let attr_quad_bounds = attribute_program_builder.add_attribute();
         let attribute_program = attribute_program_builder.build();
 
         Ok(LightDependentSpriteRenderer {
@@ -112,6 +118,7 @@ impl LightDependentSpriteRenderer {
             attr_size,
             attr_texel,
             attr_rot,
+            attr_quad_bounds,
             per_pack_attrs: HashMap::new(),
             camera_stream_info: None,
         })
@@ -136,7 +143,12 @@ impl LightDependentSpriteRenderer {
             .push(datum);
     }
 
+    pub fn num_queued(&self) -> usize {
+        self.per_pack_attrs.values().map(Vec::len).sum()
+    }
+
     pub fn draw(&mut self, lights: &PointLights, textures: &SpriteSheetTextureManager, camera_geometry: &CameraGeometry) {
+        self.shader_program.update();
         self.shader_program.activate();
         self.attribute_program.activate();
 
@@ -159,6 +171,7 @@ impl LightDependentSpriteRenderer {
 
             for datum in queued_draw.iter() {
                 let texel = textures.frame(&datum.sprite_frame_id, datum.frame, datum.reverse);
+                let quad_bounds = textures.quad_bounds(&datum.sprite_frame_id, datum.reverse);
 
                 self.attr_pos.data.push(SpritePositionAttr {
                     world_center_position: datum.world_center_position,
@@ -170,12 +183,14 @@ impl LightDependentSpriteRenderer {
                 self.attr_rot.data.push(RotationAttr {
                     unit_world_rotation_xz: glm::vec2(datum.unit_world_rotation.x as f32, -datum.unit_world_rotation.y as f32)
                 });
+                self.attr_quad_bounds.data.push(quad_bounds);
             }
 
             self.attr_pos.prepare_buffer();
             self.attr_size.prepare_buffer();
             self.attr_texel.prepare_buffer();
             self.attr_rot.prepare_buffer();
+            self.attr_quad_bounds.prepare_buffer();
 
             unsafe {
                 gl::DrawArraysInstanced(gl::POINTS, 0, 4, self.attr_pos.data.len() as GLsizei);
@@ -185,6 +200,7 @@ impl LightDependentSpriteRenderer {
             self.attr_size.data.clear();
             self.attr_texel.data.clear();
             self.attr_rot.data.clear();
+            self.attr_quad_bounds.data.clear();
         }
 
         self.per_pack_attrs.clear();
@@ -1,6 +1,12 @@
 use crate::{
     app::StatusOr,
-    file,
+    file::{
+        self,
+        Config,
+        ConfigError,
+        ConfigLoader,
+        ConfigWatcher,
+    },
     render::TextureUnit,
 };
 use gl::{
@@ -19,12 +25,39 @@ pub trait ShaderUniformKey {
     fn to_cstring(self) -> CString;
 }
 
-fn compile_shader(path: &PathBuf, shader_type: GLenum) -> StatusOr<GLuint> {
-    let slurped_shader_code = file::util::slurp_file(path)
-        .map_err(|err| format!("Error reading shader ({:?}), code: {}", path, err))?;
+// The text of one GLSL file, loaded through the ConfigWatcher so edits are picked up while running.
+pub struct ShaderSource(String);
+
+impl Config for ShaderSource {
+    fn from_path(path_buf: &PathBuf) -> Result<Self, ConfigError> {
+        let code = file::util::slurp_file(path_buf)
+            .map_err(|err| format!("Error reading shader ({:?}), code: {}", path_buf, err))?;
+        Ok(ShaderSource(code))
+    }
+}
+
+struct ShaderStage {
+    shader_type: GLenum,
+    loader: ConfigLoader<ShaderSource>,
+    source: ShaderSource,
+}
+
+impl ShaderStage {
+    fn new(config_watcher: &mut ConfigWatcher, path: &PathBuf, shader_type: GLenum) -> StatusOr<ShaderStage> {
+        let mut loader = config_watcher.watch(path.clone())?;
+        let source = loader.force_load()?;
+        Ok(ShaderStage {
+            shader_type,
+            loader,
+            source,
+        })
+    }
+}
+
+fn compile_shader(source: &ShaderSource, shader_type: GLenum) -> StatusOr<GLuint> {
     let shader_c_str =
-        CString::new(slurped_shader_code.as_str())
-            .map_err(|err| format!("Couldn't turn shader {:?} into a C string. Reason: {}", path, err))?;
+        CString::new(source.0.as_str())
+            .map_err(|err| format!("Couldn't turn shader into a C string. Reason: {}", err))?;
     unsafe {
         let shader_id = gl::CreateShader(shader_type);
         gl::ShaderSource(shader_id, 1, &shader_c_str.as_ptr(), std::ptr::null());
@@ -40,9 +73,9 @@ fn compile_shader(path: &PathBuf, shader_type: GLenum) -> StatusOr<GLuint> {
             let mut info_log = Vec::with_capacity(info_log_len as usize);
             info_log.set_len((info_log_len as usize) - 1);
             gl::GetShaderInfoLog(shader_id, info_log_len, std::ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
+            gl::DeleteShader(shader_id);
             let err_string = String::from_utf8(info_log)
-                    .map_err(|_err|
-                        format!("Shader failed to compile. Explanation was invalid UTF-8. Shader: {:?}", path))?;
+                    .map_err(|_err| String::from("Shader failed to compile. Explanation was invalid UTF-8."))?;
             Err(err_string)
         }
     }
@@ -61,54 +94,114 @@ fn compile_program(shaders: &[GLuint]) -> StatusOr<GLuint> {
         if success as GLboolean == gl::TRUE {
             Ok(program_id)
         } else {
-            let mut error_log: Vec<u8> = Vec::with_capacity(512);
-            let error_log_ptr = error_log.as_mut_slice().as_mut_ptr() as *mut i8;
-            gl::GetProgramInfoLog(program_id, error_log.capacity() as i32, std::ptr::null_mut(), error_log_ptr);
-            let err_string =
-                String::from_utf8(error_log)
-                    .map_err(|_err|
-                        String::from("Program failed to compile. Could not retrieve reason."))?;
+            let mut info_log_len = 0;
+            gl::GetProgramiv(program_id, gl::INFO_LOG_LENGTH, &mut info_log_len);
+            let mut info_log = Vec::with_capacity(info_log_len as usize);
+            info_log.set_len((info_log_len as usize).saturating_sub(1));
+            gl::GetProgramInfoLog(program_id, info_log_len, std::ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
+            gl::DeleteProgram(program_id);
+            let err_string = String::from_utf8(info_log)
+                .map_err(|_err| String::from("Program failed to link. Explanation was invalid UTF-8."))?;
             Err(err_string)
         }
     }
 }
 
+// Compiles every stage and links them. On failure, the error names the file at fault.
+fn build_program(stages: &[ShaderStage]) -> Result<GLuint, (usize, String)> {
+    let mut shaders = Vec::with_capacity(stages.len());
+    let mut result = Ok(());
+    for (idx, stage) in stages.iter().enumerate() {
+        match compile_shader(&stage.source, stage.shader_type) {
+            Ok(shader) => shaders.push(shader),
+            Err(e) => {
+                result = Err((idx, e));
+                break;
+            },
+        }
+    }
+    // Link errors usually surface in the last stage.
+    let program = result.and_then(|_| compile_program(&shaders).map_err(|e| (stages.len() - 1, e)));
+    unsafe {
+        for shader in shaders.into_iter() {
+            gl::DeleteShader(shader);
+        }
+    }
+    program
+}
+
 pub struct ShaderProgram<T> {
     pub program: GLuint,
     uniform_cache: HashMap<T, GLint>,
+    stages: Vec<ShaderStage>,
 }
 
 impl <T: std::cmp::Eq + std::hash::Hash> ShaderProgram<T> {
-    pub fn from_short_pipeline(vertex_filepath: &PathBuf, fragment_filepath: &PathBuf) -> StatusOr<ShaderProgram<T>> {
-        let vertex = compile_shader(vertex_filepath, gl::VERTEX_SHADER)?;
-        let fragment = compile_shader(fragment_filepath, gl::FRAGMENT_SHADER)?;
-        let shader_program = ShaderProgram {
-            program: compile_program(&[vertex, fragment])?,
-            uniform_cache: HashMap::new(),
-        };
-        unsafe {
-            gl::DeleteShader(vertex);
-            gl::DeleteShader(fragment);
-        }
-        Ok(shader_program)
+    pub fn from_short_pipeline(config_watcher: &mut ConfigWatcher,
+                               vertex_filepath: &PathBuf,
+                               fragment_filepath: &PathBuf) -> StatusOr<ShaderProgram<T>> {
+        Self::from_stages(vec!(
+            ShaderStage::new(config_watcher, vertex_filepath, gl::VERTEX_SHADER)?,
+            ShaderStage::new(config_watcher, fragment_filepath, gl::FRAGMENT_SHADER)?,
+        ))
     }
 
-    pub fn from_long_pipeline(vertex_filepath: &PathBuf,
+    pub fn from_long_pipeline(config_watcher: &mut ConfigWatcher,
+                              vertex_filepath: &PathBuf,
                               geometry_filepath: &PathBuf,
                               fragment_filepath: &PathBuf) -> StatusOr<ShaderProgram<T>> {
-        let vertex = compile_shader(vertex_filepath, gl::VERTEX_SHADER)?;
-        let geometry = compile_shader(geometry_filepath, gl::GEOMETRY_SHADER)?;
-        let fragment = compile_shader(fragment_filepath, gl::FRAGMENT_SHADER)?;
-        let shader_program = ShaderProgram {
-            program: compile_program(&[vertex, geometry, fragment])?,
+        Self::from_stages(vec!(
+            ShaderStage::new(config_watcher, vertex_filepath, gl::VERTEX_SHADER)?,
+            ShaderStage::new(config_watcher, geometry_filepath, gl::GEOMETRY_SHADER)?,
+            ShaderStage::new(config_watcher, fragment_filepath, gl::FRAGMENT_SHADER)?,
+        ))
+    }
+
+    fn from_stages(stages: Vec<ShaderStage>) -> StatusOr<ShaderProgram<T>> {
+        let program = build_program(&stages)
+            .map_err(|(idx, e)| format!("Shader {:?} failed to build: {}", stages[idx].loader.path(), e))?;
+        Ok(ShaderProgram {
+            program,
             uniform_cache: HashMap::new(),
-        };
-        unsafe {
-            gl::DeleteShader(vertex);
-            gl::DeleteShader(geometry);
-            gl::DeleteShader(fragment);
+            stages,
+        })
+    }
+
+    // Recompiles if any stage's file changed. Returns true if the program was replaced, in which case every
+    // uniform has to be set again. A program that fails to build is reported and the old one is kept.
+    pub fn update(&mut self) -> bool {
+        let mut changed = false;
+        for stage in self.stages.iter_mut() {
+            match stage.loader.try_load() {
+                Err(error) => stage.loader.report_error(error),
+                Ok(None) => {},
+                Ok(Some(source)) => {
+                    stage.source = source;
+                    changed = true;
+                },
+            }
+        }
+        if !changed {
+            return false;
+        }
+
+        match build_program(&self.stages) {
+            Err((idx, e)) => {
+                self.stages[idx].loader.report_error(ConfigError::from(e));
+                false
+            },
+            Ok(program) => {
+                for stage in self.stages.iter() {
+                    stage.loader.resolve_error();
+                }
+                unsafe {
+                    gl::DeleteProgram(self.program);
+                }
+                self.program = program;
+                self.uniform_cache.clear();
+                true
+            },
         }
-        Ok(shader_program)
     }
 
     pub fn activate(&self) {
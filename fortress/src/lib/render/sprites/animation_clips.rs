@@ -0,0 +1,70 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        ConfigWatcher,
+        DirSubscription,
+        SimpleConfigManager,
+    },
+    render::{
+        AnimationClip,
+        AsepriteSheet,
+        NamedSpriteSheet,
+        SpriteSheetConfig,
+    },
+};
+use std::collections::HashMap;
+
+// The clips in sprite_sheet.conf and those imported from Aseprite, for systems that animate without access to the
+// SpriteSheetTextureManager.
+pub struct AnimationClips {
+    config: SimpleConfigManager<SpriteSheetConfig>,
+    // One per sheet folder, for the Aseprite exports.
+    json_dirs: Vec<DirSubscription>,
+    imported: HashMap<NamedSpriteSheet, HashMap<String, AnimationClip>>,
+}
+
+impl AnimationClips {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
+        let config = SimpleConfigManager::from_config_resource(config_watcher, "sprite_sheet.conf")?;
+        let images_dir = file::util::resource_base().join("images");
+        let mut json_dirs = Vec::new();
+        for sprite_sheet in NamedSpriteSheet::all_values() {
+            let dir = images_dir.join(sprite_sheet.to_directory_basename());
+            if file::util::is_dir(&dir) {
+                json_dirs.push(config_watcher.subscribe_dir(&dir, "json")?);
+            }
+        }
+        let imported = AsepriteSheet::clips_in(&images_dir)?;
+        Ok(AnimationClips {
+            config,
+            json_dirs,
+            imported,
+        })
+    }
+
+    // Imported clips are read again along with sprite_sheet.conf, or when an export changes.
+    pub fn update(&mut self) -> bool {
+        // Take every subscription's events, even once one has some, so none carry over to the next update.
+        let imports_changed = self.json_dirs.iter()
+            .fold(false, |changed, subscription| !subscription.take_events().is_empty() || changed);
+        if !self.config.update() && !imports_changed {
+            return false;
+        }
+        match AsepriteSheet::clips_in(&file::util::resource_base().join("images")) {
+            Err(e) => self.config.report_error(format!("Couldn't import clips: {}", e)),
+            Ok(imported) => {
+                self.imported = imported;
+                self.config.resolve_error();
+            },
+        }
+        true
+    }
+
+    // Clips in sprite_sheet.conf win over imported ones with the same name.
+    pub fn get(&self, sprite_sheet: NamedSpriteSheet, name: &str) -> &AnimationClip {
+        self.config.get().clip(sprite_sheet, name)
+            .or_else(|| self.imported.get(&sprite_sheet)?.get(name))
+            .expect("Missing animation clip!")
+    }
+}
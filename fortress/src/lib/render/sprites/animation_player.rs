@@ -0,0 +1,55 @@
+use crate::{
+    dimensions::time::{
+        DeltaTime,
+        Microseconds,
+    },
+    render::{
+        AnimationClip,
+        LoopMode,
+    },
+};
+
+// Plays an AnimationClip. Only the time is kept, so callers pass in the clip each time and pick up reloaded clips.
+#[derive(Clone, Default)]
+pub struct AnimationPlayer {
+    time_elapsed: Microseconds,
+    // The step the last update reached, None before the first.
+    last_step: Option<usize>,
+}
+
+impl AnimationPlayer {
+    pub fn new() -> Self {
+        AnimationPlayer {
+            time_elapsed: 0,
+            last_step: None,
+        }
+    }
+
+    // Returns the events of every frame entered, in order.
+    pub fn update<'a>(&mut self, clip: &'a AnimationClip, dt: DeltaTime) -> Vec<&'a str> {
+        self.time_elapsed += dt.as_microseconds();
+        let step = clip.step_at(self.time_elapsed);
+        let first_step = self.last_step.map(|last_step| last_step + 1).unwrap_or(0);
+        self.last_step = Some(step);
+
+        (first_step..=step)
+            .flat_map(|step| clip.events_at(clip.position_at_step(step)))
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn frame(&self, clip: &AnimationClip) -> usize {
+        clip.frame_at(self.time_elapsed)
+    }
+
+    pub fn finished(&self, clip: &AnimationClip) -> bool {
+        match (clip.loop_mode, clip.duration_micros()) {
+            (LoopMode::Once, Some(duration)) => self.time_elapsed >= duration,
+            _ => false,
+        }
+    }
+
+    pub fn time_elapsed(&self) -> Microseconds {
+        self.time_elapsed
+    }
+}
@@ -1,3 +1,5 @@
+pub mod animation_clips;
+pub mod animation_player;
 pub mod sprite_sheet_texture_manager;
 
 pub use fortress_bake::render::AllPackedSpriteSheets;
@@ -7,4 +9,6 @@ pub use fortress_bake::render::SpriteSheetFrameId;
 pub use fortress_bake::render::SheetConfig;
 pub use fortress_bake::render::SpriteConfig;
 pub use fortress_bake::render::SpriteSheetConfig;
+pub use self::animation_clips::AnimationClips;
+pub use self::animation_player::AnimationPlayer;
 pub use self::sprite_sheet_texture_manager::SpriteSheetTextureManager;
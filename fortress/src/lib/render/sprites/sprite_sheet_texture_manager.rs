@@ -6,6 +6,7 @@ use crate::{
         AllPackedSpriteSheets,
         FramesInfo,
         NamedSpriteSheet,
+        QuadBounds,
         SpriteSheetFrameId,
         Texel,
         PngTexture,
@@ -15,6 +16,7 @@ use crate::{
 #[cfg(not(feature = "bake"))]
 use crate::{
     file::{
+        DirSubscription,
         SimpleConfigManager,
         self,
     },
@@ -30,6 +32,9 @@ use std::collections::HashMap;
 pub struct SpriteSheetTextureManager {
     #[cfg(not(feature = "bake"))]
     config: SimpleConfigManager<SpriteSheetConfig>,
+    // Two per sheet folder, so saving a single PNG or Aseprite export repacks the sheets.
+    #[cfg(not(feature = "bake"))]
+    image_dirs: Vec<DirSubscription>,
     textures: HashMap<NamedSpriteSheet, PngTexture>,
     frames: HashMap<SpriteSheetFrameId, FramesInfo>,
 }
@@ -38,8 +43,18 @@ impl SpriteSheetTextureManager {
     #[cfg(not(feature = "bake"))]
     pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
         let config = SimpleConfigManager::<SpriteSheetConfig>::from_config_resource(config_watcher, "sprite_sheet.conf")?;
+        let images_dir = file::util::resource_base().join("images");
+        let mut image_dirs = Vec::new();
+        for sprite_sheet in NamedSpriteSheet::all_values() {
+            let dir = images_dir.join(sprite_sheet.to_directory_basename());
+            if file::util::is_dir(&dir) {
+                image_dirs.push(config_watcher.subscribe_dir(&dir, "png")?);
+                image_dirs.push(config_watcher.subscribe_dir(&dir, "json")?);
+            }
+        }
         let mut manager = SpriteSheetTextureManager {
             config,
+            image_dirs,
             textures: HashMap::new(),
             frames: HashMap::new(),
         };
@@ -59,8 +74,15 @@ impl SpriteSheetTextureManager {
 
     #[cfg(not(feature = "bake"))]
     pub fn update(&mut self) {
-        if self.config.update() {
-            self.recompute_data().expect("Failed to update data!");
+        // Take every subscription's events, even once one has some, so none carry over to the next update.
+        let images_changed = self.image_dirs.iter()
+            .fold(false, |changed, subscription| !subscription.take_events().is_empty() || changed);
+        if self.config.update() || images_changed {
+            // Keep drawing the old sheets if the new ones don't pack.
+            match self.recompute_data() {
+                Err(e) => self.config.report_error(format!("Couldn't rebuild sprite sheets: {}", e)),
+                Ok(()) => self.config.resolve_error(),
+            }
         }
     }
 
@@ -76,6 +98,11 @@ impl SpriteSheetTextureManager {
         frame_info.texel(frame, reverse)
     }
 
+    pub fn quad_bounds(&self, frame_id: &SpriteSheetFrameId, reverse: Reverse) -> QuadBounds {
+        let frame_info = self.frames.get(frame_id).expect("Missing frame id!");
+        frame_info.quad_bounds(reverse)
+    }
+
     #[cfg(not(feature = "bake"))]
     fn recompute_data(&mut self) -> StatusOr<()> {
         let config = self.config.get();
@@ -87,10 +114,10 @@ impl SpriteSheetTextureManager {
 
     #[cfg(feature = "bake")]
     fn recompute_data(&mut self) -> StatusOr<()> {
-        let baked_sprite_sheet_config = include_bytes!(concat!(env!("OUT_DIR"), "\\config\\sprite_sheet.conf"));
-        let galaxy_ground = include_bytes!(concat!(env!("OUT_DIR"), "\\images\\GalaxyGround.png"));
-        let heroes = include_bytes!(concat!(env!("OUT_DIR"), "\\images\\Heroes.png"));
-        let sprite_sheet1 = include_bytes!(concat!(env!("OUT_DIR"), "\\images\\SpriteSheet1.png"));
+        let baked_sprite_sheet_config = include_bytes!(concat!(env!("OUT_DIR"), "/config/sprite_sheet.conf"));
+        let galaxy_ground = include_bytes!(concat!(env!("OUT_DIR"), "/images/GalaxyGround.png"));
+        let heroes = include_bytes!(concat!(env!("OUT_DIR"), "/images/Heroes.png"));
+        let sprite_sheet1 = include_bytes!(concat!(env!("OUT_DIR"), "/images/SpriteSheet1.png"));
 
         let baked_config: BakedSpriteSheetConfig =
             ron::de::from_bytes(baked_sprite_sheet_config)
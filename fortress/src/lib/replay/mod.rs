@@ -0,0 +1,8 @@
+pub mod replay_file;
+pub mod replay_player;
+pub mod replay_recorder;
+
+pub use self::replay_file::ReplayFile;
+pub use self::replay_file::ReplayFrame;
+pub use self::replay_player::ReplayPlayer;
+pub use self::replay_recorder::ReplayRecorder;
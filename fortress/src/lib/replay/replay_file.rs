@@ -0,0 +1,51 @@
+use crate::{
+    app::StatusOr,
+    control::InputFrame,
+    dimensions::time::{
+        DeltaTime,
+        Microseconds,
+    },
+    file,
+};
+use std::path::PathBuf;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplayFrame {
+    pub dt_micros: Microseconds,
+    pub input: InputFrame,
+}
+
+impl ReplayFrame {
+    pub fn dt(&self) -> DeltaTime {
+        DeltaTime::from_microseconds(self.dt_micros)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReplayFile {
+    pub seed: u64,
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl ReplayFile {
+    pub fn new(seed: u64) -> Self {
+        ReplayFile {
+            seed,
+            frames: Vec::new(),
+        }
+    }
+
+    // Replays aren't configs, so they skip the layering and validation configs go through.
+    pub fn load(path: &PathBuf) -> StatusOr<Self> {
+        let reader = file::util::reader(path)?;
+        ron::de::from_reader(reader)
+            .map_err(|e| format!("Couldn't read replay {:?}: {}", path, e))
+    }
+
+    pub fn save(&self, path: &PathBuf) -> StatusOr<()> {
+        let contents = ron::ser::to_string(self)
+            .map_err(|e| format!("Couldn't serialize replay: {:?}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Couldn't write replay {:?}: {:?}", path, e))
+    }
+}
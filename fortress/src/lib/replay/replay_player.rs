@@ -0,0 +1,34 @@
+use crate::{
+    app::StatusOr,
+    replay::{
+        ReplayFile,
+        ReplayFrame,
+    },
+};
+use std::path::PathBuf;
+
+pub struct ReplayPlayer {
+    replay: ReplayFile,
+    next_frame: usize,
+}
+
+impl ReplayPlayer {
+    pub fn load(path: &PathBuf) -> StatusOr<Self> {
+        let replay = ReplayFile::load(path)?;
+        println!("Replaying {} frames from {:?}", replay.frames.len(), path);
+        Ok(ReplayPlayer {
+            replay,
+            next_frame: 0,
+        })
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.replay.seed
+    }
+
+    pub fn next_frame(&mut self) -> Option<ReplayFrame> {
+        let frame = self.replay.frames.get(self.next_frame)?.clone();
+        self.next_frame += 1;
+        Some(frame)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    app::StatusOr,
+    control::InputFrame,
+    dimensions::time::DeltaTime,
+    replay::{
+        ReplayFile,
+        ReplayFrame,
+    },
+};
+use std::path::PathBuf;
+
+pub struct ReplayRecorder {
+    path: PathBuf,
+    replay: ReplayFile,
+    saved: bool,
+}
+
+impl ReplayRecorder {
+    pub fn new(path: PathBuf, seed: u64) -> Self {
+        println!("Recording replay to {:?}", path);
+        ReplayRecorder {
+            path,
+            replay: ReplayFile::new(seed),
+            saved: false,
+        }
+    }
+
+    pub fn record(&mut self, dt: DeltaTime, input: &InputFrame) {
+        self.saved = false;
+        self.replay.frames.push(ReplayFrame {
+            dt_micros: dt.as_microseconds(),
+            input: input.clone(),
+        });
+    }
+
+    pub fn save(&mut self) -> StatusOr<()> {
+        self.replay.save(&self.path)?;
+        self.saved = true;
+        Ok(())
+    }
+}
+
+impl Drop for ReplayRecorder {
+    // Also runs while unwinding from a panic, which is when a replay is most useful.
+    fn drop(&mut self) {
+        if !self.saved {
+            if let Err(e) = self.save() {
+                println!("{}", e);
+            }
+        }
+    }
+}
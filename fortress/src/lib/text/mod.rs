@@ -5,6 +5,7 @@ pub mod text_render_request;
 pub mod text_resolver;
 pub mod world_text_renderer;
 
+pub use fortress_bake::text::BakedGlyphSheetConfig;
 pub use fortress_bake::text::GlyphId;
 pub use fortress_bake::text::GlyphInfo;
 pub use fortress_bake::text::Locale;
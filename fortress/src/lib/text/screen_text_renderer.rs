@@ -1,6 +1,9 @@
 use crate::{
     app::StatusOr,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
@@ -55,13 +58,13 @@ pub struct ScreenTextRenderer {
 }
 
 impl ScreenTextRenderer {
-    pub fn new() -> StatusOr<Self> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
         let vertex = file::util::resource_path("shaders", "screen_text_vert.glsl");
         let geometry = file::util::resource_path("shaders", "screen_text_geo.glsl");
         let fragment = file::util::resource_path("shaders", "text_frag.glsl");
-        let mut shader_program = ShaderProgram::from_long_pipeline(&vertex, &geometry, &fragment)?;
+        let mut shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
         shader_program.activate();
-        shader_program.set_texture(UniformKey::FontTexture, TextureUnit::Texture0);
+        Self::set_fixed_uniforms(&mut shader_program);
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let attr_pos = attribute_program_builder.add_attribute();
@@ -108,8 +111,18 @@ impl ScreenTextRenderer {
         }
     }
 
+    // Only characters with baked glyphs are drawn, so request a size listed in printable_ascii_sizes.
+    pub fn queue_raw(&mut self, resolver: &TextResolver, text: &str, request: ScreenTextRequest) {
+        let mut pen = self.screen_size * glm::vec2(request.screen_position_percentage.x, request.screen_position_percentage.y);
+        self.queue_glyphs(resolver, &request, text.chars(), &mut pen);
+    }
+
     pub fn draw(&mut self, texture: &BitmapTexture) {
+        let reloaded = self.shader_program.update();
         self.shader_program.activate();
+        if reloaded {
+            Self::set_fixed_uniforms(&mut self.shader_program);
+        }
         self.attribute_program.activate();
         self.shader_program.set_vec2(UniformKey::ScreenWindowSize, self.screen_size);
         texture.activate();
@@ -158,6 +171,10 @@ impl ScreenTextRenderer {
             }
         }
     }
+
+    fn set_fixed_uniforms(shader_program: &mut ShaderProgram<UniformKey>) {
+        shader_program.set_texture(UniformKey::FontTexture, TextureUnit::Texture0);
+    }
 }
 
 #[repr(C)]
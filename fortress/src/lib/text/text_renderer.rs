@@ -1,7 +1,6 @@
 use crate::{
     app::StatusOr,
     file::{
-        self,
         ConfigWatcher,
         SimpleConfigManager,
     },
@@ -21,6 +20,10 @@ use crate::{
         WorldTextRequest,
     },
 };
+#[cfg(not(feature = "bake"))]
+use crate::file;
+#[cfg(feature = "bake")]
+use crate::text::BakedGlyphSheetConfig;
 use glm;
 
 pub struct TextRenderer {
@@ -37,15 +40,14 @@ impl TextRenderer {
 
         let (texture, resolver) = {
             let config = config.get();
-            let fonts = file::util::resource_base().join("fonts");
-            let packed = PackedGlyphSheet::new(config, &fonts)?;
+            let packed = Self::pack_glyphs(config)?;
             let texture = BitmapTexture::new(packed.image, config.texture_atlas_style, TextureUnit::Texture0);
             let resolver = TextResolver::new(config, packed.mappings);
             (texture, resolver)
         };
 
-        let screen_renderer = ScreenTextRenderer::new()?;
-        let world_renderer = WorldTextRenderer::new()?;
+        let screen_renderer = ScreenTextRenderer::new(config_watcher)?;
+        let world_renderer = WorldTextRenderer::new(config_watcher)?;
 
         Ok(TextRenderer {
             config,
@@ -58,18 +60,44 @@ impl TextRenderer {
 
     pub fn pre_update(&mut self) {
         if self.config.update() {
-            let config = self.config.get();
-            let fonts = file::util::resource_base().join("fonts");
-            match PackedGlyphSheet::new(config, &fonts) {
-                Err(e) => println!("Couldn't reload text glyphs: {:?}", e),
-                Ok(packed) => {
-                    self.texture = BitmapTexture::new(packed.image, config.texture_atlas_style, TextureUnit::Texture0);
-                    self.resolver = TextResolver::new(config, packed.mappings);
-                },
-            }
+            self.reload_glyphs();
         }
     }
 
+    #[cfg(not(feature = "bake"))]
+    fn reload_glyphs(&mut self) {
+        let config = self.config.get();
+        match Self::pack_glyphs(config) {
+            Err(e) => self.config.report_error(format!("Couldn't reload text glyphs: {}", e)),
+            Ok(packed) => {
+                self.texture = BitmapTexture::new(packed.image, config.texture_atlas_style, TextureUnit::Texture0);
+                self.resolver = TextResolver::new(config, packed.mappings);
+            },
+        }
+    }
+
+    // Reloading would only bring back the same baked atlas, so keep the glyphs and say why the edit didn't show up.
+    #[cfg(feature = "bake")]
+    fn reload_glyphs(&mut self) {
+        self.config.report_error(String::from("Glyphs are baked from text.conf at build time. Rebuild to see changes"));
+    }
+
+    #[cfg(not(feature = "bake"))]
+    fn pack_glyphs(config: &TextConfig) -> StatusOr<PackedGlyphSheet> {
+        let fonts = file::util::resource_base().join("fonts");
+        PackedGlyphSheet::new(config, &fonts)
+    }
+
+    // Glyphs for every text in text.conf at build time, so text added to the config later won't show up.
+    #[cfg(feature = "bake")]
+    fn pack_glyphs(_config: &TextConfig) -> StatusOr<PackedGlyphSheet> {
+        let baked_glyph_sheet_config = include_bytes!(concat!(env!("OUT_DIR"), "/fonts/glyph_sheet.conf"));
+        let glyph_atlas = include_bytes!(concat!(env!("OUT_DIR"), "/fonts/glyph_atlas.raw"));
+
+        let baked_config = BakedGlyphSheetConfig::from_slice(baked_glyph_sheet_config)?;
+        baked_config.into_packed(glyph_atlas)
+    }
+
     pub fn update_render_info(&mut self, camera_geometry: &CameraGeometry, screen_size: glm::IVec2) {
         self.screen_renderer.set_screen_size(screen_size);
         self.world_renderer.set_parameters(self.config.get(), camera_geometry);
@@ -80,6 +108,10 @@ impl TextRenderer {
         self.screen_renderer.queue(&self.resolver, current_locale, content, request);
     }
 
+    pub fn queue_screen_raw_text(&mut self, text: &str, request: ScreenTextRequest) {
+        self.screen_renderer.queue_raw(&self.resolver, text, request);
+    }
+
     pub fn queue_world_text(&mut self, content: impl Iterator<Item=TextContent>, request: WorldTextRequest) {
         let current_locale = self.config.get().current_locale;
         self.world_renderer.queue(&self.resolver, current_locale, content, request);
@@ -89,4 +121,9 @@ impl TextRenderer {
         self.screen_renderer.draw(&self.texture);
         self.world_renderer.draw(&self.texture, camera_geometry);
     }
+
+    // For screen text drawn outside the world's passes.
+    pub fn draw_screen(&mut self) {
+        self.screen_renderer.draw(&self.texture);
+    }
 }
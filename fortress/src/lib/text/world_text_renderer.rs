@@ -1,6 +1,9 @@
 use crate::{
     app::StatusOr,
-    file,
+    file::{
+        self,
+        ConfigWatcher,
+    },
     render::{
         attribute,
         Attribute,
@@ -63,13 +66,13 @@ pub struct WorldTextRenderer {
 }
 
 impl WorldTextRenderer {
-    pub fn new() -> StatusOr<Self> {
+    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<Self> {
         let vertex = file::util::resource_path("shaders", "world_text_vert.glsl");
         let geometry = file::util::resource_path("shaders", "world_text_geo.glsl");
         let fragment = file::util::resource_path("shaders", "text_frag.glsl");
-        let mut shader_program = ShaderProgram::from_long_pipeline(&vertex, &geometry, &fragment)?;
+        let mut shader_program = ShaderProgram::from_long_pipeline(config_watcher, &vertex, &geometry, &fragment)?;
         shader_program.activate();
-        shader_program.set_texture(UniformKey::FontTexture, TextureUnit::Texture0);
+        Self::set_fixed_uniforms(&mut shader_program);
 
         let mut attribute_program_builder = AttributeProgram::builder();
         let attr_pos = attribute_program_builder.add_attribute();
@@ -121,7 +124,11 @@ impl WorldTextRenderer {
     }
 
     pub fn draw(&mut self, texture: &BitmapTexture, camera_geometry: &CameraGeometry) {
+        let reloaded = self.shader_program.update();
         self.shader_program.activate();
+        if reloaded {
+            Self::set_fixed_uniforms(&mut self.shader_program);
+        }
         self.attribute_program.activate();
         self.shader_program.set_vec3(UniformKey::CameraRight, &self.camera_right);
         self.shader_program.set_vec3(UniformKey::CameraUp, &self.camera_up);
@@ -175,6 +182,10 @@ impl WorldTextRenderer {
             }
         }
     }
+
+    fn set_fixed_uniforms(shader_program: &mut ShaderProgram<UniformKey>) {
+        shader_program.set_texture(UniformKey::FontTexture, TextureUnit::Texture0);
+    }
 }
 
 #[repr(C)]
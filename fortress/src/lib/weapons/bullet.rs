@@ -21,10 +21,10 @@ use crate::{
     },
     players::PlayerBulletConfig,
     render::{
-        NamedSpriteSheet,
+        AnimationClips,
         FullyIlluminatedSpriteData,
+        NamedSpriteSheet,
         PointLight,
-        SpriteSheetFrameId,
     },
     weapons::BulletTraits,
 };
@@ -120,19 +120,19 @@ impl Bullet {
         })
     }
 
-    pub fn render_info(&self, config: &PlayerBulletConfig) -> FullyIlluminatedSpriteData {
+    pub fn render_info(&self, config: &PlayerBulletConfig, clips: &AnimationClips) -> FullyIlluminatedSpriteData {
         let world_position = self.get_render_world_position(config);
+        let clip = clips.get(NamedSpriteSheet::SpriteSheet1, self.bullet_traits.clip(config));
 
-        let rand_frame_offset = self.unit_random * (config.sprite_num_frames as f32 * config.sprite_frame_duration_micros as f32);
+        let rand_frame_offset = self.unit_random * clip.duration_micros().unwrap_or(0) as f32;
         let rand_frame_offset = rand_frame_offset as Microseconds;
-        let frame_duration = config.lifetime_duration_micros - self.time_left.time_left() + rand_frame_offset;
-        let frame = (frame_duration / config.sprite_frame_duration_micros) as usize;
+        let time_elapsed = config.lifetime_duration_micros - self.time_left.time_left() + rand_frame_offset;
 
         FullyIlluminatedSpriteData {
             world_center_position: world_position,
             world_half_size: glm::vec2(config.render_width, config.render_height),
-            sprite_frame_id: SpriteSheetFrameId::new(String::from(self.bullet_traits.sprite_sheet_image_name()), NamedSpriteSheet::SpriteSheet1),
-            frame,
+            sprite_frame_id: clip.frame_id(NamedSpriteSheet::SpriteSheet1),
+            frame: clip.frame_at(time_elapsed),
             unit_world_rotation: self.get_unit_direction(),
             reverse: Reverse::none(),
             bloom_intensity: config.bloom_intensity,
@@ -21,12 +21,8 @@ impl BulletTraits {
         }
     }
 
-    pub fn sprite_sheet_image_name(&self) -> &'static str {
-        match self.element {
-            BulletElement::Fire => "shooting_fireball.png",
-            BulletElement::Poison => "shooting_poisonball.png",
-            BulletElement::Ice => "shooting_iceball.png",
-        }
+    pub fn clip<'a>(&self, config: &'a PlayerBulletConfig) -> &'a str {
+        self.element.clip(config)
     }
 
     pub fn light_color(&self, config: &PlayerBulletConfig) -> glm::Vec3 {
@@ -48,6 +44,14 @@ pub enum BulletElement {
 }
 
 impl BulletElement {
+    pub fn clip(self, config: &PlayerBulletConfig) -> &str {
+        match self {
+            Self::Fire => &config.clip_fire,
+            Self::Poison => &config.clip_poison,
+            Self::Ice => &config.clip_ice,
+        }
+    }
+
     pub fn light_color(self, config: &PlayerBulletConfig) -> glm::Vec3 {
         match self {
             Self::Fire => {
@@ -15,6 +15,7 @@ use crate::{
         PlayerStats,
     },
     render::{
+        AnimationClips,
         FullyIlluminatedSpriteRenderer,
         FullyIlluminatedSpriteData,
         PointLights,
@@ -191,9 +192,9 @@ impl Weapon {
         lights.append(queue_data);
     }
 
-    pub fn queue_draw(&self, config: &PlayerBulletConfig, full_light: &mut FullyIlluminatedSpriteRenderer) {
+    pub fn queue_draw(&self, config: &PlayerBulletConfig, clips: &AnimationClips, full_light: &mut FullyIlluminatedSpriteRenderer) {
         let sprites = self.bullets.iter().map(|(_idx, bullet)| -> FullyIlluminatedSpriteData {
-            bullet.render_info(config)
+            bullet.render_info(config, clips)
         });
 
         full_light.queue(sprites);
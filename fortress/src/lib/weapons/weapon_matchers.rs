@@ -30,12 +30,12 @@ impl WeaponMatchers {
 
         match something {
             Entity::EnemyGenerator(generator_id) => {
-                if let Some(attack) = world.players.bullet_attack(player_id, bullet_id, world.rng) {
+                if let Some(attack) = world.players.bullet_attack(player_id, bullet_id, world.rng.damage()) {
                     world.enemies.enemy_generator_hit(world.audio, generator_id, attack, world.particles);
                 }
             },
             Entity::Enemy(enemy_id) => {
-                if let Some(attack) = world.players.bullet_attack(player_id, bullet_id, world.rng) {
+                if let Some(attack) = world.players.bullet_attack(player_id, bullet_id, world.rng.damage()) {
                     world.enemies.enemy_hit(enemy_id, attack, bullet_direction, world.particles);
                 }
             },
@@ -1,5 +1,8 @@
+pub mod world_renderer;
 pub mod world_state;
 pub mod world_view;
 
+pub use fortress_bake::world::WorldConfig;
+pub use self::world_renderer::WorldRenderer;
 pub use self::world_state::WorldState;
 pub use self::world_view::WorldView;
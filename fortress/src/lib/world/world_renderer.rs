@@ -0,0 +1,148 @@
+use crate::{
+    app::{
+        GameState,
+        StatusOr,
+    },
+    dimensions::time::DeltaTime,
+    file::{
+        ConfigWatcher,
+        SimpleConfigManager,
+    },
+    hud::Hud,
+    maps::render::HexRenderer,
+    particles::ParticleRenderer,
+    profiling::{
+        FrameProfiler,
+        ProfileCounter,
+        ProfileSection,
+    },
+    render::{
+        BackgroundRenderer,
+        CameraStreamInfo,
+        FullyIlluminatedSpriteRenderer,
+        LightDependentSpriteRenderer,
+        PointLights,
+        SpriteSheetTextureManager,
+        Viewport,
+    },
+    text::TextRenderer,
+    world::{
+        WorldConfig,
+        WorldState,
+    },
+};
+use glm;
+
+// The presentation half of the game. Requires a current GL context.
+pub struct WorldRenderer {
+    config_manager: SimpleConfigManager<WorldConfig>,
+    hud: Hud,
+
+    textures: SpriteSheetTextureManager,
+    text_renderer: TextRenderer,
+    background_renderer: BackgroundRenderer,
+    full_light_sprite: FullyIlluminatedSpriteRenderer,
+    light_dependent_sprite: LightDependentSpriteRenderer,
+    hex_renderer: HexRenderer,
+    particle_renderer: ParticleRenderer,
+    lights: PointLights,
+
+    map_generation: usize,
+}
+
+impl WorldRenderer {
+    pub fn new(config_watcher: &mut ConfigWatcher, world: &WorldState) -> StatusOr<WorldRenderer> {
+        Ok(WorldRenderer {
+            config_manager: SimpleConfigManager::from_config_resource(config_watcher, "world.conf")?,
+            hud: Hud::new(config_watcher)?,
+            textures: SpriteSheetTextureManager::new(config_watcher)?,
+            text_renderer: TextRenderer::new(config_watcher)?,
+            background_renderer: BackgroundRenderer::new(config_watcher)?,
+            full_light_sprite: FullyIlluminatedSpriteRenderer::new(config_watcher)?,
+            light_dependent_sprite: LightDependentSpriteRenderer::new(config_watcher)?,
+            hex_renderer: HexRenderer::new(config_watcher, world.map_config())?,
+            particle_renderer: ParticleRenderer::new(config_watcher, world.total_particle_limit())?,
+            lights: PointLights::new(config_watcher)?,
+            map_generation: world.map_generation(),
+        })
+    }
+
+    pub fn update(&mut self, world: &WorldState, dt: DeltaTime) {
+        self.config_manager.update();
+        self.textures.update();
+        self.lights.update();
+        self.background_renderer.pre_update();
+        self.text_renderer.pre_update();
+
+        if self.map_generation != world.map_generation() {
+            self.hex_renderer.rebuild_mesh(world.map_config());
+            self.map_generation = world.map_generation();
+        }
+
+        self.hud.pre_update(dt);
+        self.hud.post_update(world.prepare_player_hud_update());
+    }
+
+    pub fn clear_color(&self) -> (f32, f32, f32) {
+       self.config_manager.get().clear_color
+    }
+
+    // Interpolation alpha blends entity positions between the last two simulation steps.
+    pub fn draw(&mut self, world: &WorldState, game_state: GameState, profiler: &mut FrameProfiler, screen_size: glm::IVec2, interpolation_alpha: f64) {
+        let camera_stream_info = world.camera_stream_info();
+
+        world.set_render_interpolation(Some(interpolation_alpha));
+        self.lights.set_camera_stream_info(camera_stream_info.clone());
+        world.populate_lights(&mut self.lights);
+        self.draw_geometry(world, game_state, profiler, &camera_stream_info, screen_size, interpolation_alpha);
+        self.lights.clear();
+        world.set_render_interpolation(None);
+    }
+
+    fn draw_geometry(&mut self, world: &WorldState, game_state: GameState, profiler: &mut FrameProfiler, camera_stream_info: &CameraStreamInfo, screen_size: glm::IVec2, interpolation_alpha: f64) {
+        let geometry = world.camera().geometry(screen_size, interpolation_alpha);
+
+        self.text_renderer.update_render_info(&geometry, screen_size);
+        self.light_dependent_sprite.set_camera_stream_info(camera_stream_info.clone());
+
+        self.hud.queue_draw(game_state, &mut self.text_renderer);
+        world.queue_draw(camera_stream_info,
+                         &mut self.hex_renderer,
+                         &mut self.full_light_sprite,
+                         &mut self.light_dependent_sprite,
+                         &mut self.particle_renderer,
+                         &mut self.text_renderer);
+
+        profiler.set_count(ProfileCounter::SpritesQueued, self.full_light_sprite.num_queued() + self.light_dependent_sprite.num_queued());
+        profiler.set_count(ProfileCounter::Lights, self.lights.len());
+        profiler.set_count(ProfileCounter::Particles, self.particle_renderer.num_queued());
+
+        let scope = profiler.start(ProfileSection::DrawSprites);
+        self.background_renderer.draw(&self.textures, &geometry);
+        self.light_dependent_sprite.draw(&self.lights, &self.textures, &geometry);
+        self.full_light_sprite.draw(&self.textures, &geometry);
+        profiler.stop(scope);
+
+        let scope = profiler.start(ProfileSection::DrawHex);
+        self.hex_renderer.draw(world.map_config(), &self.textures, &self.lights, &geometry);
+        profiler.stop(scope);
+
+        // Draw particles after hex ground to not mess up transparency.
+        let scope = profiler.start(ProfileSection::DrawParticles);
+        self.particle_renderer.draw(&geometry);
+        profiler.stop(scope);
+
+        let scope = profiler.start(ProfileSection::DrawText);
+        self.text_renderer.draw(&geometry);
+        profiler.stop(scope);
+
+        // Fix viewport at the end.
+        Viewport::default(screen_size).set();
+    }
+
+    // Drawn after bloom along with the frame graph, so neither glows.
+    pub fn draw_profiler_legend(&mut self, profiler: &FrameProfiler) {
+        profiler.queue_legend(&mut self.text_renderer);
+        self.text_renderer.draw_screen();
+    }
+}
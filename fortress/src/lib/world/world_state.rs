@@ -4,52 +4,45 @@ use crate::{
     control::Controller,
     dimensions::time::DeltaTime,
     enemies::EnemySystem,
-    file::{
-        ConfigWatcher,
-        SimpleConfigManager,
-    },
-    hud::Hud,
+    file::ConfigWatcher,
+    hud::PlayerHudUpdate,
     items::ItemSystem,
-    maps::MapSystem,
-    math::RandGen,
-    particles::ParticleSystem,
+    maps::{
+        MapConfig,
+        MapSystem,
+        render::HexRenderer,
+    },
+    math::RandStreams,
+    particles::{
+        ParticleRenderer,
+        ParticleSystem,
+    },
     physics::PhysicsSimulation,
     players::{
         PlayerMatchers,
         PlayerSystem,
     },
+    profiling::{
+        FrameProfiler,
+        ProfileCounter,
+        ProfileSection,
+    },
     render::{
-        BackgroundRenderer,
         Camera,
         CameraStreamInfo,
         FullyIlluminatedSpriteRenderer,
         LightDependentSpriteRenderer,
         PointLights,
-        SpriteSheetTextureManager,
-        Viewport,
     },
     text::TextRenderer,
     weapons::WeaponMatchers,
     world::WorldView,
 };
-use glm;
-
-#[derive(Deserialize)]
-struct WorldConfig {
-    clear_color: (f32, f32, f32)
-}
+use std::path::PathBuf;
 
+// The simulation half of the game. Owns no GL resources so it can be stepped without a window.
 pub struct WorldState {
-    config_manager: SimpleConfigManager<WorldConfig>,
     camera: Camera,
-    hud: Hud,
-
-    textures: SpriteSheetTextureManager,
-    text_renderer: TextRenderer,
-    background_renderer: BackgroundRenderer,
-    full_light_sprite: FullyIlluminatedSpriteRenderer,
-    light_dependent_sprite: LightDependentSpriteRenderer,
-    lights: PointLights,
 
     map: MapSystem,
     players: PlayerSystem,
@@ -62,7 +55,7 @@ pub struct WorldState {
 }
 
 impl WorldState {
-    pub fn new(config_watcher: &mut ConfigWatcher) -> StatusOr<WorldState> {
+    pub fn new(config_watcher: &mut ConfigWatcher, map_file: Option<&PathBuf>) -> StatusOr<WorldState> {
         let mut physics_sim = PhysicsSimulation::new(config_watcher)?;
 
         physics_sim.borrow_mut().add_contact_matchers(vec!(
@@ -72,23 +65,14 @@ impl WorldState {
             PlayerMatchers::player_collected_item(),
         ));
 
-        let map = MapSystem::new(config_watcher, &mut physics_sim)?;
+        let map = MapSystem::new(config_watcher, map_file, &mut physics_sim)?;
         let players = PlayerSystem::new(config_watcher, map.spawns())?;
         let enemies = EnemySystem::new(config_watcher, map.enemy_generators(), &mut physics_sim)?;
         let items = ItemSystem::new(config_watcher, map.barrels(), &mut physics_sim)?;
         let particles = ParticleSystem::new(config_watcher)?;
-        let lights = PointLights::new()?;
 
         Ok(WorldState {
-            config_manager: SimpleConfigManager::from_config_resource(config_watcher, "world.conf")?,
             camera: Camera::new(config_watcher)?,
-            hud: Hud::new(config_watcher)?,
-            textures: SpriteSheetTextureManager::new(config_watcher)?,
-            text_renderer: TextRenderer::new(config_watcher)?,
-            background_renderer: BackgroundRenderer::new(config_watcher)?,
-            full_light_sprite: FullyIlluminatedSpriteRenderer::new()?,
-            light_dependent_sprite: LightDependentSpriteRenderer::new()?,
-            lights,
             map,
             players,
             enemies,
@@ -98,33 +82,30 @@ impl WorldState {
         })
     }
 
-    pub fn update(&mut self, audio: &AudioPlayer, controller: &Controller, rng: &mut RandGen, dt: DeltaTime) {
-        self.config_manager.update();
-        self.textures.update();
-
+    pub fn update(&mut self, audio: &AudioPlayer, controller: &Controller, rng: &mut RandStreams, profiler: &mut FrameProfiler, dt: DeltaTime) {
         // Pre-update.
         {
-            self.background_renderer.pre_update();
-            self.text_renderer.pre_update();
+            let scope = profiler.start(ProfileSection::WorldPreUpdate);
             self.camera.pre_update(dt);
-            self.hud.pre_update(dt);
 
             if self.map.pre_update(&mut self.physics_sim) {
-                self.players.respawn(self.map.spawns());
-                self.enemies.respawn(self.map.enemy_generators(), &mut self.physics_sim);
-                self.items.respawn(self.map.barrels(), &mut self.physics_sim);
-                self.particles.respawn();
+                self.respawn_entities();
             } else {
-                self.players.pre_update(audio, controller, &mut self.particles, rng, self.camera.mut_shake(), &mut self.physics_sim, dt);
+                self.players.pre_update(audio, controller, &mut self.particles, rng.bullets(), self.camera.mut_shake(), &mut self.physics_sim, dt);
                 let player_locs = self.players.player_locs();
-                self.enemies.pre_update(controller, dt, player_locs, &mut self.physics_sim);
+                self.enemies.pre_update(audio, controller, dt, player_locs, &mut self.physics_sim);
                 self.items.pre_update(controller, self.map.barrels(), &mut self.physics_sim);
                 self.particles.pre_update(dt);
             }
+            profiler.stop(scope);
         }
 
         {
+            let scope = profiler.start(ProfileSection::PhysicsStep);
             self.physics_sim.borrow_mut().step(dt);
+            profiler.stop(scope);
+
+            let scope = profiler.start(ProfileSection::ProcessContacts);
             self.physics_sim.borrow().process_contacts(WorldView {
                 audio,
                 players: &mut self.players,
@@ -134,64 +115,96 @@ impl WorldState {
                 rng,
                 dt
             });
+            profiler.stop(scope);
         }
 
         // Post-update.
         {
+            let scope = profiler.start(ProfileSection::WorldPostUpdate);
             self.players.post_update();
             self.camera.post_update(self.players.player_locs(), dt);
-            self.items.post_update(rng, &mut self.physics_sim);
+            self.items.post_update(rng.loot(), &mut self.physics_sim);
             self.enemies.post_update(audio, &mut self.items, self.camera.mut_shake(), &mut self.physics_sim);
 
             let camera_stream_info = self.camera.stream_info(self.map.hex_cell_length());
-            self.particles.post_update(&camera_stream_info, rng);
-
-            self.hud.post_update(self.players.prepare_player_hud_update());
+            let particle_scope = profiler.start(ProfileSection::ParticleUpdate);
+            self.particles.post_update(&camera_stream_info, rng.particles());
+            profiler.stop(particle_scope);
+            profiler.stop(scope);
         }
+
+        profiler.set_count(ProfileCounter::PhysicsBodies, self.physics_sim.borrow().num_bodies());
     }
 
-    pub fn clear_color(&self) -> (f32, f32, f32) {
-       self.config_manager.get().clear_color
+    // Lets controllers join or leave while the simulation isn't being stepped.
+    pub fn ingest_controller_events(&mut self, audio: &AudioPlayer, controller: &Controller) {
+        self.players.ingest_controller_events(audio, controller, &mut self.physics_sim);
     }
 
-    pub fn draw(&mut self, screen_size: glm::IVec2) {
-        let camera_stream_info = self.camera.stream_info(self.map.hex_cell_length());
+    // Redeploys the map and everything on it for a fresh session.
+    pub fn restart(&mut self) {
+        self.map.redeploy(&mut self.physics_sim);
+        self.respawn_entities();
+    }
 
-        self.populate_lights(&camera_stream_info);
-        self.draw_geometry(&camera_stream_info, screen_size);
-        self.lights.clear();
+    pub fn level_complete(&self) -> bool {
+        self.enemies.all_cleared()
     }
 
-    fn populate_lights(&mut self, camera_stream_info: &CameraStreamInfo) {
-        self.lights.set_camera_stream_info(camera_stream_info.clone());
-        self.map.populate_lights(&mut self.lights);
-        self.players.populate_lights(self.items.config(), &mut self.lights);
-        self.enemies.populate_lights(&mut self.lights);
-        self.items.populate_lights(&mut self.lights);
+    fn respawn_entities(&mut self) {
+        self.players.respawn(self.map.spawns());
+        self.enemies.respawn(self.map.enemy_generators(), &mut self.physics_sim);
+        self.items.respawn(self.map.barrels(), &mut self.physics_sim);
+        self.particles.respawn();
     }
 
-    fn draw_geometry(&mut self, camera_stream_info: &CameraStreamInfo, screen_size: glm::IVec2) {
-        let geometry = self.camera.geometry(screen_size);
+    // While set, physics body positions are blended between the previous and current step for drawing.
+    pub fn set_render_interpolation(&self, alpha: Option<f64>) {
+        self.physics_sim.borrow_mut().set_render_interpolation(alpha);
+    }
+
+    pub fn camera(&self) -> &Camera {
+        &self.camera
+    }
 
-        self.text_renderer.update_render_info(&geometry, screen_size);
-        self.light_dependent_sprite.set_camera_stream_info(camera_stream_info.clone());
+    pub fn camera_stream_info(&self) -> CameraStreamInfo {
+        self.camera.stream_info(self.map.hex_cell_length())
+    }
 
-        self.hud.queue_draw(&mut self.text_renderer);
-        self.map.queue_draw(&camera_stream_info, &mut self.full_light_sprite);
-        self.players.queue_draw(&mut self.full_light_sprite, &mut self.light_dependent_sprite);
-        self.enemies.queue_draw(&mut self.light_dependent_sprite, &mut self.text_renderer);
-        self.items.queue_draw(&mut self.full_light_sprite);
+    pub fn map_config(&self) -> &MapConfig {
+        self.map.config()
+    }
 
-        self.background_renderer.draw(&self.textures, &geometry);
-        self.light_dependent_sprite.draw(&self.lights, &self.textures, &geometry);
-        self.full_light_sprite.draw(&self.textures, &geometry);
-        self.map.draw_terrain(&self.textures, &self.lights, &geometry);
+    pub fn map_generation(&self) -> usize {
+        self.map.generation()
+    }
 
-        // Draw particles after hex ground to not mess up transparency.
-        self.particles.draw(&geometry);
-        self.text_renderer.draw(&geometry);
+    pub fn total_particle_limit(&self) -> usize {
+        self.particles.total_particle_limit()
+    }
+
+    pub fn prepare_player_hud_update(&self) -> PlayerHudUpdate {
+        self.players.prepare_player_hud_update()
+    }
+
+    pub fn populate_lights(&self, lights: &mut PointLights) {
+        self.map.populate_lights(lights);
+        self.players.populate_lights(self.items.config(), lights);
+        self.enemies.populate_lights(lights);
+        self.items.populate_lights(lights);
+    }
 
-        // Fix viewport at the end.
-        Viewport::default(screen_size).set();
+    pub fn queue_draw(&self,
+                      camera_stream_info: &CameraStreamInfo,
+                      hex_renderer: &mut HexRenderer,
+                      full_light: &mut FullyIlluminatedSpriteRenderer,
+                      light_dependent: &mut LightDependentSpriteRenderer,
+                      particles: &mut ParticleRenderer,
+                      text: &mut TextRenderer) {
+        self.map.queue_draw(camera_stream_info, hex_renderer, full_light);
+        self.players.queue_draw(full_light, light_dependent);
+        self.enemies.queue_draw(light_dependent, text);
+        self.items.queue_draw(full_light);
+        self.particles.queue_draw(particles);
     }
 }
@@ -3,7 +3,7 @@ use crate::{
     dimensions::time::DeltaTime,
     enemies::EnemySystem,
     items::ItemSystem,
-    math::RandGen,
+    math::RandStreams,
     particles::ParticleSystem,
     players::PlayerSystem,
 };
@@ -14,7 +14,7 @@ pub struct WorldView<'a> {
     pub enemies: &'a mut EnemySystem,
     pub items: &'a mut ItemSystem,
     pub particles: &'a mut ParticleSystem,
-    pub rng: &'a mut RandGen,
+    pub rng: &'a mut RandStreams,
     pub dt: DeltaTime,
 }
 
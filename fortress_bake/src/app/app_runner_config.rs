@@ -0,0 +1,62 @@
+use crate::{
+    app::WindowMode,
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    render::BloomConfig,
+};
+
+#[derive(Deserialize)]
+pub struct AppRunnerConfig {
+    pub app: AppConfig,
+    pub bloom: BloomConfig,
+    pub timestep: FixedTimestepConfig,
+    pub headless: HeadlessConfig,
+}
+
+#[derive(Deserialize)]
+pub struct AppConfig {
+    pub window_size: (i32, i32),
+    pub window_mode: WindowMode,
+    pub sleep_to_frame_micros: i64,
+    pub enable_quit: bool,
+    pub rng_seed: Option<u64>,
+}
+
+impl AppConfig {
+    // A seed given on the command line wins over app.conf. Without either, the caller seeds from the clock.
+    pub fn resolve_rng_seed(&self, seed_override: Option<u64>) -> Option<u64> {
+        seed_override.or(self.rng_seed)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct FixedTimestepConfig {
+    pub step_micros: i64,
+    pub max_steps_per_frame: usize,
+    pub max_frame_micros: i64,
+}
+
+#[derive(Deserialize)]
+pub struct HeadlessConfig {
+    // Simulation steps of timestep.step_micros each, as in the game.
+    pub num_steps: usize,
+}
+
+impl Validate for AppRunnerConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("app.window_size.0", self.app.window_size.0);
+        validator.positive("app.window_size.1", self.app.window_size.1);
+        validator.at_least("app.sleep_to_frame_micros", self.app.sleep_to_frame_micros, 0);
+        validator.nested("timestep", &self.timestep);
+    }
+}
+
+impl Validate for FixedTimestepConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("step_micros", self.step_micros);
+        validator.positive("max_steps_per_frame", self.max_steps_per_frame);
+        validator.at_least("max_frame_micros", self.max_frame_micros, self.step_micros);
+    }
+}
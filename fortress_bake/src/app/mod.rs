@@ -1,3 +1,12 @@
+pub mod app_runner_config;
+pub mod resource_lint;
 pub mod status;
+pub mod window_mode;
 
-pub use self::status::StatusOr;
\ No newline at end of file
+pub use self::app_runner_config::AppConfig;
+pub use self::app_runner_config::AppRunnerConfig;
+pub use self::app_runner_config::FixedTimestepConfig;
+pub use self::app_runner_config::HeadlessConfig;
+pub use self::resource_lint::lint_resources;
+pub use self::status::StatusOr;
+pub use self::window_mode::WindowMode;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::{
+    app::{
+        AppRunnerConfig,
+        StatusOr,
+    },
+    audio::AudioConfig,
+    control::GamepadConfig,
+    enemies::EnemySystemConfig,
+    file::{
+        self,
+        AssetReferences,
+        ConfigError,
+        ConfigLint,
+    },
+    hud::HudConfig,
+    items::ItemConfig,
+    maps::{
+        MapConfig,
+        MapFileFragment,
+    },
+    particles::ParticleConfig,
+    physics::SimulationConfig,
+    players::PlayerSystemConfig,
+    profiling::ProfilerConfig,
+    render::{
+        AsepriteSheet,
+        BackgroundRendererConfig,
+        CameraConfig,
+        PointLightsConfig,
+        SpriteSheetConfig,
+    },
+    text::TextConfig,
+    world::WorldConfig,
+};
+
+// Loads every config and map the game reads and checks every asset they name, without SDL or GL. Add new configs here as
+// well as where the game loads them.
+pub fn lint_resources() -> StatusOr<ConfigLint> {
+    let mut lint = ConfigLint::new();
+
+    lint.check_config::<AppRunnerConfig>("app.conf");
+    lint.check_config::<AudioConfig>("audio.conf");
+    lint.check_config::<BackgroundRendererConfig>("background_renderer.conf");
+    lint.check_config::<CameraConfig>("camera.conf");
+    lint.check_config::<GamepadConfig>("gamepad.conf");
+    lint.check_config::<HudConfig>("hud.conf");
+    lint.check_config::<ItemConfig>("item.conf");
+    lint.check_config::<PointLightsConfig>("lights.conf");
+    lint.check_config::<ParticleConfig>("particle.conf");
+    lint.check_config::<SimulationConfig>("physics_simulation.conf");
+    lint.check_config::<ProfilerConfig>("profiler.conf");
+    lint.check_config::<WorldConfig>("world.conf");
+
+    lint.check_config::<MapConfig>("map.conf");
+    MapFileFragment::lint(&mut lint);
+
+    let mut references = AssetReferences::new();
+    if let Some(player_config) = lint.check_config::<PlayerSystemConfig>("player.conf") {
+        references.add_player_config(&player_config);
+    }
+    if let Some(enemy_config) = lint.check_config::<EnemySystemConfig>("enemy.conf") {
+        references.add_enemy_config(&enemy_config);
+    }
+    if let Some(sprite_sheet_config) = lint.check_config::<SpriteSheetConfig>("sprite_sheet.conf") {
+        references.add_clip_images(&sprite_sheet_config);
+        let config_path = file::util::config_path("sprite_sheet.conf");
+        match AsepriteSheet::clips_in(&file::util::resource_base().join("images")) {
+            Err(e) => lint.report(config_path, ConfigError::from(format!("Couldn't import clips: {}", e))),
+            Ok(imported) => references.check_clips(&sprite_sheet_config, &imported, &config_path, &mut lint),
+        }
+    }
+    references.check_files(&file::util::resource_base(), &mut lint);
+    if let Some(text_config) = lint.check_config::<TextConfig>("text.conf") {
+        references.check_texts(&text_config, &file::util::config_path("text.conf"), &mut lint);
+    }
+
+    lint.check_unused_configs()?;
+    Ok(lint)
+}
@@ -0,0 +1,19 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum WindowMode {
+    Windowed,
+    // Exclusive fullscreen at the window's size.
+    Fullscreen,
+    // A borderless window covering the desktop.
+    Borderless,
+}
+
+impl WindowMode {
+    // Toggling into a mode we're already in returns to a window.
+    pub fn toggle(self, mode: WindowMode) -> WindowMode {
+        if self == mode {
+            WindowMode::Windowed
+        } else {
+            mode
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+#[derive(Deserialize)]
+pub struct AudioConfig {
+    pub sound_volume: f64
+}
+
+impl Validate for AudioConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.in_range("sound_volume", self.sound_volume, 0.0, 1.0);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod audio_config;
+pub mod sound;
+
+pub use self::audio_config::AudioConfig;
+pub use self::sound::Sound;
\ No newline at end of file
@@ -18,7 +18,11 @@ pub enum Sound {
 
 impl Sound {
     pub fn to_path_buf(self) -> PathBuf {
-        let filename = match self {
+        file::util::resource_path("audio", self.file_name())
+    }
+
+    pub fn file_name(self) -> &'static str {
+        match self {
             Sound::BarrelDestroy => "barrel_destroy.wav",
             Sound::BarrelHit => "barrel_hit.wav",
             Sound::CollectItem => "collect_item.wav",
@@ -29,11 +33,15 @@ impl Sound {
             Sound::JoinGame => "join_game.wav",
             Sound::ShootSingleFireball => "shoot_single_fireball.wav",
             Sound::ShootSpecial => "shoot_special.wav",
-        };
-        file::util::resource_path("audio", filename)
+        }
     }
 
     pub fn all_sounds() -> impl Iterator<Item = Self> {
         enum_iterator::all::<Self>()
     }
+
+    // By variant name, as animation clip events refer to sounds.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all_sounds().find(|sound| format!("{:?}", sound) == name)
+    }
 }
\ No newline at end of file
@@ -1,38 +1,205 @@
 use crate::{
     app::StatusOr,
     bake::{
+        BakeManifest,
+        BakeSummary,
+        ContentHash,
         InputDirectories,
         InputOutput,
         OutputDirectories,
+        SpriteSheetSummary,
     },
-    file::Config,
+    enemies::EnemySystemConfig,
+    file::{
+        self,
+        AssetReferences,
+        Config,
+        ConfigLint,
+        ResourceArchive,
+    },
+    players::PlayerSystemConfig,
     render::{
-        AllPackedSpriteSheets,
+        AsepriteSheet,
         BakedSpriteSheetConfig,
+        NamedSpriteSheet,
+        PackedSpriteSheet,
         SpriteSheetConfig,
     },
+    text::{
+        BakedGlyphSheetConfig,
+        PackedGlyphSheet,
+        TextConfig,
+    },
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
 };
 
-fn save_sprite_sheets(input: &InputDirectories, output: &OutputDirectories) -> StatusOr<()> {
-    let sprite_sheet_config = SpriteSheetConfig::from_path(&input.config.join("sprite_sheet.conf"))?;
-    let all_packed_sprite_sheets = AllPackedSpriteSheets::read_from_files(&sprite_sheet_config, &input.images)?;
-
-    for (named_sprite_sheet, (image, _style)) in all_packed_sprite_sheets.images.iter() {
-        let image_filepath = output.images.join(format!("{}.png", named_sprite_sheet.to_directory_basename()));
-        image.save_to_file(image_filepath)?;
+fn hash_dir(hash: &mut ContentHash, dir: &PathBuf, extension: &str) -> StatusOr<()> {
+    let mut base_names = file::util::files_in_dir_ending_with(dir, extension)?;
+    base_names.sort();
+    for base_name in base_names.iter() {
+        hash.file(base_name, &dir.join(base_name))?;
     }
+    Ok(())
+}
 
+// Only sheets whose images, Aseprite exports or sprite_sheet.conf changed are repacked. The rest keep their frames from the last bake.
+fn save_sprite_sheets(input: &InputDirectories, output: &OutputDirectories, manifest: &mut BakeManifest, summary: &mut BakeSummary) -> StatusOr<()> {
+    let config_path = input.config.join("sprite_sheet.conf");
+    summary.add_input(config_path.clone());
+    let sprite_sheet_config = SpriteSheetConfig::from_path(&config_path)?;
     let baked_config_filepath = output.config.join("sprite_sheet.conf");
-    let baked_config = BakedSpriteSheetConfig::new(sprite_sheet_config, all_packed_sprite_sheets.frames);
+    // Without the last bake's frames, nothing can be reused.
+    let previous_frames = std::fs::read(&baked_config_filepath).ok()
+        .and_then(|bytes| ron::de::from_bytes::<BakedSpriteSheetConfig>(&bytes).ok())
+        .map(|baked_config| baked_config.frames);
+
+    let mut frames = HashMap::new();
+    for named_sprite_sheet in NamedSpriteSheet::all_values() {
+        let basename = named_sprite_sheet.to_directory_basename();
+        let sheet_dir = input.images.join(&basename);
+        summary.add_input(sheet_dir.clone());
+
+        let mut hash = ContentHash::new();
+        hash.file("sprite_sheet.conf", &config_path)?;
+        hash_dir(&mut hash, &sheet_dir, ".png")?;
+        hash_dir(&mut hash, &sheet_dir, ".json")?;
+        let input_hash = hash.finish();
+
+        let output_key = format!("sprite_sheet/{}", basename);
+        let image_filepath = output.images.join(format!("{}.png", basename));
+        if let Some(ref previous_frames) = previous_frames {
+            if manifest.is_current(&output_key, input_hash, &[&image_filepath]) {
+                let previous_sheet_frames: Vec<_> = previous_frames.iter()
+                    .filter(|(frame_id, _)| frame_id.sprite_sheet() == named_sprite_sheet)
+                    .cloned()
+                    .collect();
+                summary.sprite_sheets.push(SpriteSheetSummary {
+                    sprite_sheet: named_sprite_sheet,
+                    sprite_count: previous_sheet_frames.len(),
+                    packed: None,
+                });
+                frames.extend(previous_sheet_frames);
+                continue;
+            }
+        }
+
+        let sheet_config = sprite_sheet_config.sheets.get(&named_sprite_sheet)
+            .ok_or(format!("No sheet data for {:?}", named_sprite_sheet))?;
+        let packed = PackedSpriteSheet::new(sheet_config, &input.images, named_sprite_sheet)?;
+        packed.image.save_to_file(image_filepath)?;
+        summary.sprite_sheets.push(SpriteSheetSummary {
+            sprite_sheet: named_sprite_sheet,
+            sprite_count: packed.mappings.len(),
+            packed: Some((packed.image.size(), packed.fill_ratio)),
+        });
+        frames.extend(packed.mappings.into_iter());
+        manifest.record(&output_key, input_hash);
+    }
+
+    let baked_config = BakedSpriteSheetConfig::new(sprite_sheet_config, frames);
+    let baked_config_string = ron::ser::to_string(&baked_config)
+        .map_err(|e| format!("{:?}", e))?;
+    std::fs::write(baked_config_filepath, baked_config_string)
+        .map_err(|e| format!("{:?}", e))?;
+
+    Ok(())
+}
+
+fn save_glyph_sheet(input: &InputDirectories, output: &OutputDirectories, manifest: &mut BakeManifest, summary: &mut BakeSummary) -> StatusOr<()> {
+    let config_path = input.config.join("text.conf");
+    summary.add_input(config_path.clone());
+    summary.add_input(input.fonts.clone());
+
+    let mut hash = ContentHash::new();
+    hash.file("text.conf", &config_path)?;
+    hash_dir(&mut hash, &input.fonts, ".ttf")?;
+    let input_hash = hash.finish();
+
+    let atlas_filepath = output.fonts.join("glyph_atlas.raw");
+    let baked_config_filepath = output.fonts.join("glyph_sheet.conf");
+    if manifest.is_current("glyph_sheet", input_hash, &[&atlas_filepath, &baked_config_filepath]) {
+        return Ok(());
+    }
+
+    let text_config = TextConfig::from_path(&config_path)?;
+    let packed_glyph_sheet = PackedGlyphSheet::new(&text_config, &input.fonts)?;
+    std::fs::write(atlas_filepath, packed_glyph_sheet.image.image_bytes())
+        .map_err(|e| format!("{:?}", e))?;
+
+    let baked_config = BakedGlyphSheetConfig::new(&packed_glyph_sheet);
     let baked_config_string = ron::ser::to_string(&baked_config)
         .map_err(|e| format!("{:?}", e))?;
     std::fs::write(baked_config_filepath, baked_config_string)
         .map_err(|e| format!("{:?}", e))?;
 
+    manifest.record("glyph_sheet", input_hash);
+    summary.glyph_count = Some(packed_glyph_sheet.mappings.len());
     Ok(())
 }
 
-pub fn run(input_output: InputOutput) -> StatusOr<()> {
-    save_sprite_sheets(&input_output.input, &input_output.output)?;
+// Release builds can ship this single file in place of res/. It holds everything, so any change to res/ reruns it.
+fn save_resource_archive(input: &InputDirectories, archive: &PathBuf, manifest: &mut BakeManifest, summary: &mut BakeSummary) -> StatusOr<()> {
+    summary.add_input(input.resource_base.clone());
+
+    let mut hash = ContentHash::new();
+    for path in ResourceArchive::input_files(&input.resource_base)? {
+        let relative = path.strip_prefix(&input.resource_base)
+            .map_err(|e| format!("{:?} isn't in {:?}: {}", path, input.resource_base, e))?;
+        hash.file(&ResourceArchive::key(relative), &path)?;
+    }
+    let input_hash = hash.finish();
+
+    if manifest.is_current("resource_archive", input_hash, &[archive]) {
+        return Ok(());
+    }
+    ResourceArchive::pack(&input.resource_base, archive)?;
+    manifest.record("resource_archive", input_hash);
+    summary.archive_packed = true;
     Ok(())
 }
+
+// Fails the bake on any sprite, clip, sound or text the game names but res/ lacks.
+fn check_asset_references(input: &InputDirectories, summary: &mut BakeSummary) -> StatusOr<()> {
+    let text_config_path = input.config.join("text.conf");
+    let sprite_sheet_config_path = input.config.join("sprite_sheet.conf");
+    let player_config_path = input.config.join("player.conf");
+    let enemy_config_path = input.config.join("enemy.conf");
+    summary.add_input(player_config_path.clone());
+    summary.add_input(enemy_config_path.clone());
+
+    let text_config = TextConfig::from_path(&text_config_path)?;
+    let sprite_sheet_config = SpriteSheetConfig::from_path(&sprite_sheet_config_path)?;
+    let mut references = AssetReferences::new();
+    references.add_clip_images(&sprite_sheet_config);
+    references.add_player_config(&PlayerSystemConfig::from_path(&player_config_path)?);
+    references.add_enemy_config(&EnemySystemConfig::from_path(&enemy_config_path)?);
+    let imported = AsepriteSheet::clips_in(&input.images)?;
+
+    let mut lint = ConfigLint::new();
+    references.check_clips(&sprite_sheet_config, &imported, &sprite_sheet_config_path, &mut lint);
+    references.check_files(&input.resource_base, &mut lint);
+    references.check_texts(&text_config, &text_config_path, &mut lint);
+    lint.finish()
+}
+
+pub fn run(input_output: &InputOutput) -> StatusOr<BakeSummary> {
+    let input = &input_output.input;
+    let output = &input_output.output;
+    let mut summary = BakeSummary::default();
+    check_asset_references(input, &mut summary)?;
+    let mut manifest = BakeManifest::load(&output.manifest);
+
+    // Save after each step so a failure later on doesn't throw away work that succeeded.
+    save_sprite_sheets(input, output, &mut manifest, &mut summary)?;
+    manifest.save(&output.manifest)?;
+    save_glyph_sheet(input, output, &mut manifest, &mut summary)?;
+    manifest.save(&output.manifest)?;
+    if let Some(ref archive) = output.archive {
+        save_resource_archive(input, archive, &mut manifest, &mut summary)?;
+        manifest.save(&output.manifest)?;
+    }
+    Ok(summary)
+}
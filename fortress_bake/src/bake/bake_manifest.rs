@@ -0,0 +1,80 @@
+use crate::app::StatusOr;
+use std::{
+    collections::HashMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+// FNV-1a. Stable across runs and toolchains, unlike std's DefaultHasher.
+pub struct ContentHash {
+    state: u64,
+}
+
+impl ContentHash {
+    pub fn new() -> ContentHash {
+        ContentHash {
+            state: 0xcbf29ce484222325,
+        }
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        for byte in bytes.iter() {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(0x100000001b3);
+        }
+        self
+    }
+
+    // Hashes the name and length too, so renaming or splitting files changes the hash.
+    pub fn file(&mut self, name: &str, path: &Path) -> StatusOr<&mut Self> {
+        let contents = std::fs::read(path)
+            .map_err(|e| format!("Couldn't read {:?} for hashing: {}", path, e))?;
+        self.bytes(name.as_bytes());
+        self.bytes(&(contents.len() as u64).to_le_bytes());
+        self.bytes(&contents);
+        Ok(self)
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for ContentHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Input hashes of every output from the last bake, kept in OUT_DIR.
+#[derive(Default, Serialize, Deserialize)]
+pub struct BakeManifest {
+    input_hashes: HashMap<String, u64>,
+}
+
+impl BakeManifest {
+    // A missing or unreadable manifest just means everything gets baked.
+    pub fn load(path: &PathBuf) -> BakeManifest {
+        std::fs::read(path).ok()
+            .and_then(|bytes| ron::de::from_bytes(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &PathBuf) -> StatusOr<()> {
+        let manifest_string = ron::ser::to_string(self)
+            .map_err(|e| format!("{:?}", e))?;
+        std::fs::write(path, manifest_string)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    // True if the output was last baked from the same inputs and is still there.
+    pub fn is_current(&self, output: &str, input_hash: u64, output_paths: &[&PathBuf]) -> bool {
+        self.input_hashes.get(output) == Some(&input_hash) && output_paths.iter().all(|path| path.is_file())
+    }
+
+    pub fn record(&mut self, output: &str, input_hash: u64) {
+        self.input_hashes.insert(String::from(output), input_hash);
+    }
+}
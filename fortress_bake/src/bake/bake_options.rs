@@ -0,0 +1,75 @@
+use crate::app::StatusOr;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: fortress_bake [OPTIONS] RES_DIR OUT_DIR
+       fortress_bake --lint [--config-dir DIR] RES_DIR
+
+Bakes sprite sheets, glyph sheets and the resource archive from RES_DIR into OUT_DIR, which must be outside RES_DIR.
+
+Options:
+  --watch           Bake again whenever anything in RES_DIR changes
+  --lint            Check every config, map and named asset in RES_DIR instead of baking, print all problems and
+                    exit non-zero if any
+  --config-dir DIR  With --lint, also check the configs in DIR merged over RES_DIR/config
+  --help            Print this message";
+
+#[derive(Default)]
+pub struct BakeOptions {
+    pub resource_dir: PathBuf,
+    pub output_dir: PathBuf,
+    pub config_override_dir: Option<PathBuf>,
+    pub watch: bool,
+    pub lint: bool,
+    pub show_help: bool,
+}
+
+impl BakeOptions {
+    // Expects the program name to have been skipped already.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> StatusOr<BakeOptions> {
+        let mut options = BakeOptions::default();
+        let mut dirs = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--watch" => options.watch = true,
+                "--lint" => options.lint = true,
+                "--config-dir" => {
+                    let dir = args.next().ok_or(format!("{} requires a value", arg))?;
+                    options.config_override_dir = Some(PathBuf::from(dir));
+                },
+                "--help" | "-h" => options.show_help = true,
+                _ if arg.starts_with('-') => return Err(format!("Unknown argument {}\n\n{}", arg, USAGE)),
+                _ => dirs.push(PathBuf::from(arg)),
+            }
+        }
+        if options.show_help {
+            return Ok(options);
+        }
+
+        if options.lint {
+            if options.watch {
+                return Err(format!("--watch can't be combined with --lint\n\n{}", USAGE));
+            }
+            return match <[PathBuf; 1]>::try_from(dirs) {
+                Ok([resource_dir]) => {
+                    options.resource_dir = resource_dir;
+                    Ok(options)
+                },
+                Err(dirs) => Err(format!("Expected RES_DIR, got {} directories\n\n{}", dirs.len(), USAGE)),
+            };
+        }
+        if options.config_override_dir.is_some() {
+            return Err(format!("--config-dir only applies to --lint\n\n{}", USAGE));
+        }
+
+        match <[PathBuf; 2]>::try_from(dirs) {
+            Ok([resource_dir, output_dir]) => {
+                options.resource_dir = resource_dir;
+                options.output_dir = output_dir;
+                Ok(options)
+            },
+            Err(dirs) => Err(format!("Expected RES_DIR and OUT_DIR, got {} directories\n\n{}", dirs.len(), USAGE)),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::render::NamedSpriteSheet;
+use std::{
+    fmt,
+    path::PathBuf,
+};
+
+// What a bake read and wrote. build.rs turns the inputs into rerun-if-changed lines; the bake command prints the rest.
+#[derive(Default)]
+pub struct BakeSummary {
+    pub inputs: Vec<PathBuf>,
+    pub sprite_sheets: Vec<SpriteSheetSummary>,
+    // None if the glyph sheet was already current.
+    pub glyph_count: Option<usize>,
+    pub archive_packed: bool,
+}
+
+pub struct SpriteSheetSummary {
+    pub sprite_sheet: NamedSpriteSheet,
+    pub sprite_count: usize,
+    // Sheet size and the fraction of it sprites cover, or None if the sheet was already current.
+    pub packed: Option<((usize, usize), f32)>,
+}
+
+impl BakeSummary {
+    pub fn add_input(&mut self, path: PathBuf) {
+        self.inputs.push(path);
+    }
+}
+
+impl fmt::Display for BakeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for sheet in self.sprite_sheets.iter() {
+            match sheet.packed {
+                None => writeln!(f, "{:?}: {} sprites, up to date", sheet.sprite_sheet, sheet.sprite_count)?,
+                Some(((width, height), fill_ratio)) => {
+                    writeln!(f, "{:?}: {} sprites in {}x{}, {:.1}% filled", sheet.sprite_sheet, sheet.sprite_count, width, height, fill_ratio * 100.0)?
+                },
+            }
+        }
+        match self.glyph_count {
+            None => writeln!(f, "Glyphs: up to date")?,
+            Some(glyph_count) => writeln!(f, "Glyphs: {} packed", glyph_count)?,
+        }
+        write!(f, "Resource archive: {}", if self.archive_packed { "packed" } else { "up to date" })
+    }
+}
@@ -1,5 +1,11 @@
-use crate::app::StatusOr;
-use std::path::PathBuf;
+use crate::{
+    app::StatusOr,
+    file,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
 
 pub struct InputOutput {
     pub input: InputDirectories,
@@ -7,9 +13,24 @@ pub struct InputOutput {
 }
 
 impl InputOutput {
-    pub fn new(project_root: PathBuf) -> StatusOr<Self> {
+    pub fn new(project_root: PathBuf, pack_archive: bool) -> StatusOr<Self> {
         let input = InputDirectories::new(project_root)?;
-        let output = OutputDirectories::new()?;
+        let output = OutputDirectories::new(pack_archive)?;
+
+        Ok(InputOutput {
+            input,
+            output,
+        })
+    }
+
+    // For baking outside of a build script. The archive would pack the outputs along with everything else, so they can't
+    // go in the resource folder.
+    pub fn from_dirs(resource_base: &Path, output_dir: PathBuf) -> StatusOr<Self> {
+        let input = InputDirectories::from_resource_base(resource_base)?;
+        if resolve_path(&output_dir)?.starts_with(&input.resource_base) {
+            return Err(format!("Output dir {:?} can't be inside the resource base {:?}", output_dir, input.resource_base));
+        }
+        let output = OutputDirectories::in_dir(output_dir, true)?;
 
         Ok(InputOutput {
             input,
@@ -18,7 +39,21 @@ impl InputOutput {
     }
 }
 
+// Canonicalizes the part of path that exists, since the output dir may not have been created yet.
+fn resolve_path(path: &Path) -> StatusOr<PathBuf> {
+    let path = std::env::current_dir()
+        .map_err(|e| format!("Couldn't get current dir: {}", e))?
+        .join(path);
+    for ancestor in path.ancestors() {
+        if let (Ok(canonical), Ok(rest)) = (ancestor.canonicalize(), path.strip_prefix(ancestor)) {
+            return Ok(canonical.join(rest));
+        }
+    }
+    Ok(path)
+}
+
 pub struct InputDirectories {
+    pub resource_base: PathBuf,
     pub config: PathBuf,
     pub images: PathBuf,
     pub fonts: PathBuf,
@@ -26,8 +61,11 @@ pub struct InputDirectories {
 
 impl InputDirectories {
     pub fn new(project_root: PathBuf) -> StatusOr<Self> {
-        let resource_base = project_root
-            .join("res")
+        Self::from_resource_base(&project_root.join("res"))
+    }
+
+    pub fn from_resource_base(resource_base: &Path) -> StatusOr<Self> {
+        let resource_base = resource_base
             .canonicalize()
             .map_err(|e| format! ("Couldn't canonicalize resource base: {}", e))?;
 
@@ -35,6 +73,7 @@ impl InputDirectories {
             config: resource_base.join("config"),
             images: resource_base.join("images"),
             fonts: resource_base.join("fonts"),
+            resource_base,
         })
     }
 }
@@ -43,17 +82,28 @@ pub struct OutputDirectories {
     pub config: PathBuf,
     pub images: PathBuf,
     pub fonts: PathBuf,
+    // None when nothing will read the archive, so the bake skips it.
+    pub archive: Option<PathBuf>,
+    pub manifest: PathBuf,
 }
 
 impl OutputDirectories {
-    pub fn new() -> StatusOr<Self> {
+    pub fn new(pack_archive: bool) -> StatusOr<Self> {
         let output_dir_string = std::env::var("OUT_DIR")
             .map_err(|e| format!("{:?}", e))?;
-        let output_dir = PathBuf::from(output_dir_string);
+        Self::in_dir(PathBuf::from(output_dir_string), pack_archive)
+    }
 
+    pub fn in_dir(output_dir: PathBuf, pack_archive: bool) -> StatusOr<Self> {
         let config = output_dir.join("config");
         let images = output_dir.join("images");
         let fonts = output_dir.join("fonts");
+        let archive = if pack_archive {
+            Some(output_dir.join(file::util::RESOURCE_ARCHIVE_NAME))
+        } else {
+            None
+        };
+        let manifest = output_dir.join("bake_manifest.conf");
 
         for directory in [&config, &images, &fonts].into_iter() {
             std::fs::create_dir_all(directory)
@@ -64,6 +114,8 @@ impl OutputDirectories {
             config,
             images,
             fonts,
+            archive,
+            manifest,
         })
     }
 }
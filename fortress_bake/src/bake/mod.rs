@@ -1,13 +1,18 @@
+#[cfg(feature = "rasterize")]
 pub mod bake;
-pub mod no_bake;
+pub mod bake_manifest;
+pub mod bake_options;
+pub mod bake_summary;
 pub mod input_output;
 
-#[cfg(feature = "bake")]
+#[cfg(feature = "rasterize")]
 pub use self::bake::run;
 
-#[cfg(not(feature = "bake"))]
-pub use self::no_bake::run;
-
+pub use self::bake_manifest::BakeManifest;
+pub use self::bake_manifest::ContentHash;
+pub use self::bake_options::BakeOptions;
+pub use self::bake_summary::BakeSummary;
+pub use self::bake_summary::SpriteSheetSummary;
 pub use self::input_output::InputDirectories;
 pub use self::input_output::InputOutput;
 pub use self::input_output::OutputDirectories;
@@ -1,8 +0,0 @@
-use crate::{
-    app::StatusOr,
-    bake::InputOutput,
-};
-
-pub fn run(_input_output: InputOutput) -> StatusOr<()> {
-    Ok(())
-}
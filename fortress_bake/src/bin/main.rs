@@ -0,0 +1,106 @@
+extern crate fortress_bake;
+extern crate notify;
+
+use fortress_bake::{
+    app::{
+        self,
+        StatusOr,
+    },
+    bake::{
+        self,
+        bake_options::USAGE,
+        BakeOptions,
+        InputOutput,
+    },
+    file,
+};
+use notify::{
+    DebouncedEvent,
+    Watcher,
+};
+use std::{
+    sync::mpsc,
+    time::Duration,
+};
+
+fn main() -> StatusOr<()> {
+    let options = BakeOptions::from_args(std::env::args().skip(1))?;
+    if options.show_help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    if options.lint {
+        return lint(&options);
+    }
+
+    let input_output = InputOutput::from_dirs(&options.resource_dir, options.output_dir.clone())?;
+    if !options.watch {
+        return run(&input_output);
+    }
+
+    // A failed bake is only reported, so fixing the problem and saving rebakes.
+    if let Err(e) = run(&input_output) {
+        println!("{}", e);
+    }
+    watch(&input_output)
+}
+
+fn run(input_output: &InputOutput) -> StatusOr<()> {
+    let summary = bake::run(input_output)?;
+    println!("{}", summary);
+    Ok(())
+}
+
+// Loads configs the way the game does, so it goes through the resource base rather than an InputOutput.
+fn lint(options: &BakeOptions) -> StatusOr<()> {
+    file::util::init_resource_base(Some(&options.resource_dir))?;
+    if let Some(ref dir) = options.config_override_dir {
+        file::util::set_config_override_dir(dir)?;
+    }
+    let lint = app::lint_resources()?;
+    if lint.finish().is_err() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn watch(input_output: &InputOutput) -> StatusOr<()> {
+    // Editors write files in bursts, so wait for them to settle before baking.
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::watcher(tx, Duration::from_millis(200))
+        .map_err(|e| format!("Error creating watcher: {}", e))?;
+    let resource_base = &input_output.input.resource_base;
+    watcher.watch(resource_base, notify::RecursiveMode::Recursive)
+        .map_err(|e| format!("Error watching {:?}: {}", resource_base, e))?;
+    // The output dir is never inside the resource folder, so the bake's own writes don't trigger another.
+    println!("Watching {:?}", resource_base);
+
+    loop {
+        let event = rx.recv()
+            .map_err(|e| format!("Watcher stopped: {}", e))?;
+        let mut changed = is_input_change(&event);
+        for event in rx.try_iter() {
+            changed |= is_input_change(&event);
+        }
+        if !changed {
+            continue;
+        }
+
+        println!("Baking");
+        if let Err(e) = run(input_output) {
+            println!("{}", e);
+        }
+    }
+}
+
+fn is_input_change(event: &DebouncedEvent) -> bool {
+    match event {
+        DebouncedEvent::Create(_) | DebouncedEvent::Write(_) | DebouncedEvent::Remove(_) | DebouncedEvent::Rename(_, _) => true,
+        DebouncedEvent::Rescan => true,
+        DebouncedEvent::Error(e, path) => {
+            println!("Error watching {:?}: {}", path, e);
+            false
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,15 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+#[derive(Clone, Deserialize)]
+pub struct GamepadConfig {
+    pub axis_threshold: f32,
+}
+
+impl Validate for GamepadConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.in_range("axis_threshold", self.axis_threshold, 0.0, 1.0);
+    }
+}
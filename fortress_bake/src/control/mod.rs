@@ -0,0 +1,3 @@
+pub mod gamepad_config;
+
+pub use self::gamepad_config::GamepadConfig;
@@ -0,0 +1,5 @@
+#[derive(Copy, Clone, Deserialize, Eq, Hash, PartialEq)]
+pub enum Criticality {
+    Normal,
+    Crit,
+}
@@ -1,3 +1,7 @@
+pub mod criticality;
+pub mod lr_direction;
 pub mod reverse;
 
+pub use self::criticality::Criticality;
+pub use self::lr_direction::LrDirection;
 pub use self::reverse::Reverse;
\ No newline at end of file
@@ -1,5 +1,9 @@
 use crate::{
     dimensions::Criticality,
+    file::{
+        ConfigValidator,
+        Validate,
+    },
     text::RasterSize,
 };
 use std::collections::HashMap;
@@ -35,9 +39,9 @@ pub struct EnemyGeneratorConfig {
 pub struct EnemyConfig {
     pub slab_initial_capacity_guess: usize,
     pub starting_health: i64,
-    pub dying_duration_micros: i64,
-    pub dying_frame_duration_micros: i64,
-    pub walk_frame_duration_micros: i64,
+    // Clips in SpriteSheet1.
+    pub dying_clip: String,
+    pub walk_clip: String,
     pub stop_and_hit_distance: f64,
     pub anger_distance: f64,
     pub move_speed: f64,
@@ -60,3 +64,31 @@ pub struct DamageTextConfig {
     pub shadow_color: (f32, f32, f32),
     pub shadow_offset: (f32, f32, f32),
 }
+
+impl Validate for EnemySystemConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.nested("generator", &self.generator);
+        validator.nested("enemy", &self.enemy);
+        validator.positive("damage_text.text_expiry_duration_micros", self.damage_text.text_expiry_duration_micros);
+    }
+}
+
+impl Validate for EnemyGeneratorConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("starting_health", self.starting_health);
+        validator.at_least("cooldown_duration_micros", self.cooldown_duration_micros, 0);
+        validator.positive("physical_radius", self.physical_radius);
+        validator.positive("physical_density", self.physical_density);
+        // The sprite frame is picked by scaling health into [0, num_sprite_frames).
+        validator.positive("num_sprite_frames", self.num_sprite_frames);
+    }
+}
+
+impl Validate for EnemyConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("starting_health", self.starting_health);
+        validator.at_least("move_speed", self.move_speed, 0.0);
+        validator.positive("physical_radius", self.physical_radius);
+        validator.positive("physical_density", self.physical_density);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod enemy_config;
+
+pub use self::enemy_config::DamageTextConfig;
+pub use self::enemy_config::EnemyConfig;
+pub use self::enemy_config::EnemyGeneratorConfig;
+pub use self::enemy_config::EnemySystemConfig;
@@ -0,0 +1,151 @@
+use crate::{
+    audio::Sound,
+    enemies::EnemySystemConfig,
+    file::{
+        self,
+        ConfigError,
+        ConfigLint,
+    },
+    players::PlayerSystemConfig,
+    render::{
+        AnimationClip,
+        NamedSprite,
+        NamedSpriteSheet,
+        SpriteSheetConfig,
+        SpriteSheetFrameId,
+    },
+    text::{
+        Locale,
+        NamedText,
+        TextConfig,
+    },
+};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::Path,
+};
+
+// Every sprite, clip, sound and text the game asks for by name. A missing one otherwise only shows up when it's first
+// drawn or played, as a panic or as nothing at all.
+pub struct AssetReferences {
+    sprites: Vec<(SpriteSheetFrameId, String)>,
+    clips: Vec<(NamedSpriteSheet, String, String)>,
+}
+
+impl Default for AssetReferences {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssetReferences {
+    // Starts with the references compiled into the game.
+    pub fn new() -> AssetReferences {
+        let sprites = NamedSprite::all_values()
+            .map(|sprite| (sprite.frame_id(), format!("NamedSprite::{:?}", sprite)))
+            .collect();
+
+        AssetReferences {
+            sprites,
+            clips: Vec::new(),
+        }
+    }
+
+    // For sprites named in configs.
+    pub fn add_sprite(&mut self, frame_id: SpriteSheetFrameId, referenced_from: impl Display) {
+        self.sprites.push((frame_id, referenced_from.to_string()));
+    }
+
+    // Every clip's image must exist, whether or not anything plays it.
+    pub fn add_clip_images(&mut self, sprite_sheet_config: &SpriteSheetConfig) {
+        for (sprite_sheet, sheet) in sprite_sheet_config.sheets.iter() {
+            for (name, clip) in sheet.clips.iter() {
+                self.add_sprite(clip.frame_id(*sprite_sheet), format!("clip {} in sprite_sheet.conf", name));
+            }
+        }
+    }
+
+    // For clips named in configs.
+    pub fn add_clip(&mut self, sprite_sheet: NamedSpriteSheet, name: &str, referenced_from: impl Display) {
+        self.clips.push((sprite_sheet, String::from(name), referenced_from.to_string()));
+    }
+
+    // Hero clips and extra sprites, and bullet clips.
+    pub fn add_player_config(&mut self, player_config: &PlayerSystemConfig) {
+        for (hero, hero_config) in player_config.hero.iter() {
+            let referenced_from = format!("hero[{:?}] in player.conf", hero);
+            self.add_clip(NamedSpriteSheet::Heroes, &hero_config.idle_clip, &referenced_from);
+            self.add_clip(NamedSpriteSheet::Heroes, &hero_config.walking_clip, &referenced_from);
+            if let Some(ref render_extra) = hero_config.render_extra {
+                for image_name in vec!(&render_extra.idle_image_extra_name, &render_extra.walking_image_extra_name) {
+                    self.add_sprite(SpriteSheetFrameId::new(image_name.clone(), NamedSpriteSheet::Heroes), &referenced_from);
+                }
+            }
+        }
+        for clip in vec!(&player_config.bullet.clip_fire, &player_config.bullet.clip_poison, &player_config.bullet.clip_ice) {
+            self.add_clip(NamedSpriteSheet::SpriteSheet1, clip, "bullet in player.conf");
+        }
+    }
+
+    pub fn add_enemy_config(&mut self, enemy_config: &EnemySystemConfig) {
+        self.add_clip(NamedSpriteSheet::SpriteSheet1, &enemy_config.enemy.walk_clip, "enemy in enemy.conf");
+        self.add_clip(NamedSpriteSheet::SpriteSheet1, &enemy_config.enemy.dying_clip, "enemy in enemy.conf");
+    }
+
+    // Reports every clip added with add_clip that neither sprite_sheet.conf nor an import defines.
+    pub fn check_clips(&self,
+                       sprite_sheet_config: &SpriteSheetConfig,
+                       imported: &HashMap<NamedSpriteSheet, HashMap<String, AnimationClip>>,
+                       sprite_sheet_config_path: &Path,
+                       lint: &mut ConfigLint) {
+        for (sprite_sheet, name, referenced_from) in self.clips.iter() {
+            let is_imported = imported.get(sprite_sheet).map(|clips| clips.contains_key(name)).unwrap_or(false);
+            if sprite_sheet_config.clip(*sprite_sheet, name).is_none() && !is_imported {
+                let message = format!("sheets[{:?}] has no clip {} for {}", sprite_sheet, name, referenced_from);
+                lint.report(sprite_sheet_config_path.to_path_buf(), ConfigError::from(message));
+            }
+        }
+    }
+
+    // Reports every missing sprite image and sound file under resource_base.
+    pub fn check_files(&self, resource_base: &Path, lint: &mut ConfigLint) {
+        let images_dir = resource_base.join("images");
+        for (frame_id, referenced_from) in self.sprites.iter() {
+            // Imported sprites are named "<json file>#<tag>".
+            let file_name = frame_id.name().split('#').next().unwrap_or_default();
+            let path = images_dir.join(frame_id.sprite_sheet().to_directory_basename()).join(file_name);
+            lint.mark_checked(path.clone());
+            if !file::util::is_file(&path) {
+                lint.report(path, ConfigError::from(format!("Missing sprite referenced by {}", referenced_from)));
+            }
+        }
+
+        let audio_dir = resource_base.join("audio");
+        for sound in Sound::all_sounds() {
+            let path = audio_dir.join(sound.file_name());
+            lint.mark_checked(path.clone());
+            if !file::util::is_file(&path) {
+                lint.report(path, ConfigError::from(format!("Missing sound for Sound::{:?}", sound)));
+            }
+        }
+    }
+
+    // Reports every NamedText a Locale has no string for or that has no sizes to pack glyphs at.
+    pub fn check_texts(&self, text_config: &TextConfig, text_config_path: &Path, lint: &mut ConfigLint) {
+        lint.mark_checked(text_config_path.to_path_buf());
+        for locale in Locale::all_values() {
+            match text_config.localized_text.get(&locale) {
+                None => lint.report(text_config_path.to_path_buf(), ConfigError::from(format!("localized_text has no entry for {:?}", locale))),
+                Some(texts) => {
+                    for text in NamedText::all_values().filter(|text| !texts.contains_key(text)) {
+                        lint.report(text_config_path.to_path_buf(), ConfigError::from(format!("localized_text[{:?}] is missing {:?}", locale, text)));
+                    }
+                },
+            }
+        }
+        for text in NamedText::all_values().filter(|text| !text_config.text_sizes.contains_key(text)) {
+            lint.report(text_config_path.to_path_buf(), ConfigError::from(format!("text_sizes is missing {:?}, so none of its glyphs are packed", text)));
+        }
+    }
+}
@@ -1,6 +1,14 @@
 use crate::{
     app::StatusOr,
-    file
+    file::{
+        ConfigError,
+        ConfigValidator,
+        DirEvent,
+        DirSubscription,
+        ReloadErrors,
+        Validate,
+        self,
+    },
 };
 use notify::{
     self,
@@ -11,22 +19,59 @@ use std::{
     cell::RefCell,
     collections::HashMap,
     marker::PhantomData,
-    path::PathBuf,
+    path::{
+        Path,
+        PathBuf,
+    },
     sync::mpsc,
     time::Duration,
     rc::Rc,
 };
 
 pub trait Config: Sized {
-    fn from_path(path_buf: &PathBuf) -> StatusOr<Self>;
+    fn from_path(path_buf: &PathBuf) -> Result<Self, ConfigError>;
+
+    // Later layers override earlier ones. Types that can't be merged take the last layer whole.
+    fn from_layers(layers: &[PathBuf]) -> Result<Self, ConfigError> {
+        match layers.last() {
+            Some(path_buf) => Self::from_path(path_buf),
+            None => Err(ConfigError::from(String::from("No config layers to load"))),
+        }
+    }
 }
 
-impl<T: DeserializeOwned> Config for T {
-    fn from_path(path_buf: &PathBuf) -> StatusOr<T> {
+impl<T: DeserializeOwned + Validate> Config for T {
+    fn from_path(path_buf: &PathBuf) -> Result<T, ConfigError> {
         let reader = file::util::reader(path_buf)?;
-        ron::de::from_reader(reader)
-            .map_err(|e| format!("Couldn't parse config {:?}: {}", path_buf, e))
+        let config: T = ron::de::from_reader(reader)
+            .map_err(|e| ConfigError {
+                message: format!("Couldn't parse config {:?}: {}", path_buf, e.code),
+                line_column: Some((e.position.line, e.position.col)),
+            })?;
+        validated(config)
     }
+
+    fn from_layers(layers: &[PathBuf]) -> Result<T, ConfigError> {
+        if layers.len() <= 1 {
+            return match layers.first() {
+                Some(path_buf) => Self::from_path(path_buf),
+                None => Err(ConfigError::from(String::from("No config layers to load"))),
+            };
+        }
+
+        // Positions in the merged text don't point anywhere useful, so leave them out.
+        let merged = file::ron_layers::merge_layers(layers)?;
+        let config: T = ron::de::from_str(&merged)
+            .map_err(|e| ConfigError::from(format!("Couldn't parse config merged from {:?}: {}", layers, e.code)))?;
+        validated(config)
+    }
+}
+
+fn validated<T: Validate>(config: T) -> Result<T, ConfigError> {
+    let mut validator = ConfigValidator::new();
+    config.validate(&mut validator);
+    validator.finish()?;
+    Ok(config)
 }
 
 struct DirtyBit {
@@ -46,32 +91,58 @@ impl DirtyBit {
 
     pub fn set_clean(&self) { self.is_dirty.replace(false); }
 
+    pub fn share(&self) -> DirtyBit {
+        DirtyBit {
+            is_dirty: Rc::clone(&self.is_dirty)
+        }
+    }
+
     pub fn is_dirty(&self) -> bool { *self.is_dirty.borrow()}
 }
 
 pub struct ConfigLoader<T> {
     dirty: DirtyBit,
     path: PathBuf,
+    // Set for configs in res/config, which are merged with the profile and user layers.
+    layered_name: Option<String>,
+    reload_errors: ReloadErrors,
     _phantom: PhantomData<T>,
 }
 
 impl<T> ConfigLoader<T> {
-    fn new(dirty: DirtyBit, path: PathBuf) -> ConfigLoader<T> {
+    fn new(dirty: DirtyBit, path: PathBuf, layered_name: Option<String>, reload_errors: ReloadErrors) -> ConfigLoader<T> {
         ConfigLoader {
             dirty,
             path,
+            layered_name,
+            reload_errors,
             _phantom: PhantomData
         }
     }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn report_error(&self, error: ConfigError) {
+        self.reload_errors.report(self.path.clone(), error);
+    }
+
+    pub fn resolve_error(&self) {
+        self.reload_errors.resolve(&self.path);
+    }
 }
 
 impl<T: Config> ConfigLoader<T> {
-    pub fn force_load(&mut self) -> StatusOr<T> {
+    pub fn force_load(&mut self) -> Result<T, ConfigError> {
         self.dirty.set_clean();
-        T::from_path(&self.path)
+        match self.layered_name {
+            Some(ref name) => T::from_layers(&file::util::config_layer_paths(name)),
+            None => T::from_path(&self.path),
+        }
     }
 
-    pub fn try_load(&mut self) -> StatusOr<Option<T>> {
+    pub fn try_load(&mut self) -> Result<Option<T>, ConfigError> {
         if self.dirty.is_dirty() {
             let parsed = self.force_load()?;
             Ok(Some(parsed))
@@ -83,78 +154,240 @@ impl<T: Config> ConfigLoader<T> {
 }
 
 pub struct ConfigWatcher {
-    _watcher: notify::RecommendedWatcher,
+    watcher: notify::RecommendedWatcher,
+    watched_roots: Vec<PathBuf>,
     fs_events: mpsc::Receiver<notify::DebouncedEvent>,
-    children: HashMap<PathBuf, DirtyBit>
+    // Keyed by normalized path so events match however the path was spelled.
+    children: HashMap<PathBuf, Vec<DirtyBit>>,
+    // Paired with the config's file name.
+    layered: Vec<(String, DirtyBit)>,
+    dir_subscriptions: Vec<DirSubscription>,
+    reload_errors: ReloadErrors,
 }
 
 impl ConfigWatcher {
     pub fn new() -> StatusOr<ConfigWatcher> {
         let (tx, rx) = mpsc::channel();
-        let mut watcher = notify::watcher(tx, Duration::from_millis(30))
+        let watcher = notify::watcher(tx, Duration::from_millis(30))
             .map_err(|e| format!("Error creating watcher: {}", e))?;
-        watcher.watch(file::util::resource_base(), notify::RecursiveMode::Recursive)
-            .map_err(|e| format!("Error watching resource dir: {}", e))?;
 
-        Ok(ConfigWatcher {
-            _watcher: watcher,
+        let mut config_watcher = ConfigWatcher {
+            watcher,
+            watched_roots: Vec::new(),
             fs_events: rx,
-            children: HashMap::new()
-        })
+            children: HashMap::new(),
+            layered: Vec::new(),
+            dir_subscriptions: Vec::new(),
+            reload_errors: ReloadErrors::default(),
+        };
+        config_watcher.watch_root(&file::util::resource_base())?;
+        if let Some(override_dir) = file::util::config_override_dir() {
+            config_watcher.watch_root(&override_dir)?;
+        }
+        Ok(config_watcher)
     }
 
     pub fn watch<T>(&mut self, path: PathBuf) -> StatusOr<ConfigLoader<T>> {
-        if !path.exists() {
+        if !file::util::is_file(&path) && !file::util::is_dir(&path) {
             return Err(format!("Cannot watch path because it doesn't exist: {:?}", path));
         }
+        if let Some(parent) = Self::normalize(&path).parent() {
+            self.watch_root(parent)?;
+        }
         let dirty_bit = DirtyBit::new();
-        let dirty_bit_copy = DirtyBit { is_dirty: Rc::clone(&dirty_bit.is_dirty) };
-        self.children.insert(path.clone(), dirty_bit);
-        Ok(ConfigLoader::new(dirty_bit_copy, path))
+        self.children.entry(Self::normalize(&path)).or_default().push(dirty_bit.share());
+        Ok(ConfigLoader::new(dirty_bit, path, None, self.reload_errors.clone()))
+    }
+
+    // Queues events for files with the extension as they're added to, changed in or removed from dir.
+    pub fn subscribe_dir(&mut self, dir: &Path, extension: &str) -> StatusOr<DirSubscription> {
+        if !file::util::is_dir(dir) {
+            return Err(format!("Cannot subscribe to dir because it doesn't exist: {:?}", dir));
+        }
+        let dir = Self::normalize(dir);
+        self.watch_root(&dir)?;
+        let subscription = DirSubscription::new(dir, extension, self.reload_errors.clone());
+        self.dir_subscriptions.push(subscription.share());
+        Ok(subscription)
+    }
+
+    // Watches res/config/<name> along with its profile and user layers.
+    pub fn watch_layered<T>(&mut self, config_file_name: &str) -> StatusOr<ConfigLoader<T>> {
+        let path = file::util::config_path(config_file_name);
+        if !file::util::is_file(&path) {
+            return Err(format!("Cannot watch config because it doesn't exist: {:?}", path));
+        }
+        let dirty_bit = DirtyBit::new();
+        for candidate in file::util::config_layer_candidates(config_file_name) {
+            self.children.entry(Self::normalize(&candidate)).or_default().push(dirty_bit.share());
+        }
+        self.layered.push((String::from(config_file_name), dirty_bit.share()));
+        Ok(ConfigLoader::new(dirty_bit, path, Some(String::from(config_file_name)), self.reload_errors.clone()))
+    }
+
+    // Switches profile and reloads every layered config on the next update.
+    pub fn set_config_profile(&mut self, profile: Option<&str>) -> StatusOr<()> {
+        file::util::set_config_profile(profile)?;
+        for (_, dirty_bit) in self.layered.iter() {
+            dirty_bit.set_dirty();
+        }
+        Ok(())
     }
 
+    // Steps through no profile, then each profile in res/config/profiles in name order.
+    pub fn cycle_config_profile(&mut self) -> StatusOr<Option<String>> {
+        let profiles = file::util::config_profiles();
+        let next = match file::util::config_profile() {
+            None => profiles.first().cloned(),
+            Some(current) => profiles.iter()
+                .skip_while(|profile| **profile != current)
+                .nth(1)
+                .cloned(),
+        };
+        self.set_config_profile(next.as_deref())?;
+        Ok(next)
+    }
+
+    pub fn reload_errors(&self) -> ReloadErrors {
+        self.reload_errors.clone()
+    }
+
+    // Editors that save through a temp file and a rename show up as Create or Rename rather than Write.
     pub fn update(&self) {
         for event in self.fs_events.try_iter() {
-            if let notify::DebouncedEvent::Write(path) = event {
-                self.set_dirty(path)
+            match event {
+                notify::DebouncedEvent::Create(path) => {
+                    self.set_dirty(&path);
+                    self.notify_dirs(&path, DirEvent::Added);
+                },
+                notify::DebouncedEvent::Write(path) => {
+                    self.set_dirty(&path);
+                    self.notify_dirs(&path, DirEvent::Changed);
+                },
+                notify::DebouncedEvent::Remove(path) => {
+                    self.set_dirty(&path);
+                    self.notify_dirs(&path, DirEvent::Removed);
+                },
+                notify::DebouncedEvent::Rename(from, to) => {
+                    self.set_dirty(&from);
+                    self.set_dirty(&to);
+                    self.notify_dirs(&from, DirEvent::Removed);
+                    self.notify_dirs(&to, DirEvent::Added);
+                },
+                notify::DebouncedEvent::Rescan => {
+                    for dirty_bit in self.children.values().flatten() {
+                        dirty_bit.set_dirty();
+                    }
+                    for subscription in self.dir_subscriptions.iter() {
+                        subscription.push(DirEvent::Rescan);
+                    }
+                },
+                notify::DebouncedEvent::Error(e, path) => println!("Error watching {:?}: {}", path, e),
+                _ => {},
             }
         }
     }
 
-    fn set_dirty(&self, p: PathBuf) {
-        if let Some(dirty_bit) = self.children.get(&p) {
-            (*dirty_bit).set_dirty();
+    fn set_dirty(&self, p: &Path) {
+        let path = Self::normalize(p);
+        if let Some(dirty_bits) = self.children.get(&path) {
+            for dirty_bit in dirty_bits.iter() {
+                dirty_bit.set_dirty();
+            }
         }
-    } }
+        self.set_profile_layer_dirty(&path);
+    }
+
+    // Profile layers are matched by where they sit rather than registered up front, so profiles created
+    // after startup are watched too. A profile dir appearing or going away can change any layered config.
+    fn set_profile_layer_dirty(&self, path: &Path) {
+        let profiles_dir = Self::normalize(&file::util::config_profiles_dir());
+        let parent = match path.parent() {
+            Some(parent) => parent,
+            None => return,
+        };
+        if parent == profiles_dir {
+            for (_, dirty_bit) in self.layered.iter() {
+                dirty_bit.set_dirty();
+            }
+        } else if parent.parent() == Some(profiles_dir.as_path()) {
+            for (config_file_name, dirty_bit) in self.layered.iter() {
+                if path.file_name().map_or(false, |file_name| file_name == config_file_name.as_str()) {
+                    dirty_bit.set_dirty();
+                }
+            }
+        }
+    }
+
+    fn notify_dirs(&self, p: &Path, event: impl Fn(PathBuf) -> DirEvent) {
+        let path = Self::normalize(p);
+        for subscription in self.dir_subscriptions.iter() {
+            if subscription.matches(&path) {
+                subscription.push(event(path.clone()));
+            }
+        }
+    }
+
+    // Files outside the watched roots, like a map given on the command line, get their dir watched too.
+    // Files in the resource archive never change, so there's nothing to watch.
+    fn watch_root(&mut self, dir: &Path) -> StatusOr<()> {
+        if file::util::is_archived(dir) {
+            return Ok(());
+        }
+        let dir = Self::normalize(dir);
+        if self.watched_roots.iter().any(|root| dir.starts_with(root)) {
+            return Ok(());
+        }
+        self.watcher.watch(&dir, notify::RecursiveMode::Recursive)
+            .map_err(|e| format!("Error watching {:?}: {}", dir, e))?;
+        self.watched_roots.push(dir);
+        Ok(())
+    }
+
+    // Removed and not-yet-created files can't be canonicalized, so fall back to their parent.
+    fn normalize(path: &Path) -> PathBuf {
+        if let Ok(canonical) = path.canonicalize() {
+            return canonical;
+        }
+        match (path.parent().and_then(|parent| parent.canonicalize().ok()), path.file_name()) {
+            (Some(parent), Some(file_name)) => parent.join(file_name),
+            _ => path.to_path_buf(),
+        }
+    }
+}
 
 pub struct SimpleConfigManager<T> {
-    config_file_name: PathBuf,
     config_loader: ConfigLoader<T>,
     config: T
 }
 
 impl<T: Config> SimpleConfigManager<T> {
     pub fn from_resource_path(config_watcher: &mut ConfigWatcher, path: PathBuf) -> StatusOr<SimpleConfigManager<T>> {
-        let mut config_loader = config_watcher.watch(path.clone())?;
+        let mut config_loader = config_watcher.watch(path)?;
         let config = config_loader.force_load()?;
         Ok(SimpleConfigManager {
-            config_file_name: path,
             config_loader,
             config
         })
     }
 
     pub fn from_config_resource(config_watcher: &mut ConfigWatcher, config_file_name: &'static str) -> StatusOr<SimpleConfigManager<T>> {
-        let config_path = file::util::resource_path("config", config_file_name);
-        Self::from_resource_path(config_watcher, config_path)
+        let mut config_loader = config_watcher.watch_layered(config_file_name)?;
+        let config = config_loader.force_load()?;
+        Ok(SimpleConfigManager {
+            config_loader,
+            config
+        })
     }
 
+    // On a failed reload the previous config stays in place and the error goes to the watcher's ReloadErrors.
     pub fn update(&mut self) -> bool {
         let reloaded = self.config_loader.try_load();
         match reloaded {
-            Err(message) => println!("Error reloading {:?}: {}", self.config_file_name, message),
+            Err(error) => self.config_loader.report_error(error),
             Ok(None) => {},
             Ok(Some(config)) => {
+                self.config_loader.resolve_error();
                 self.config = config;
                 return true;
             }
@@ -162,6 +395,15 @@ impl<T: Config> SimpleConfigManager<T> {
         false
     }
 
+    // For failures after parsing, like an image that won't pack.
+    pub fn report_error(&self, message: String) {
+        self.config_loader.report_error(ConfigError::from(message));
+    }
+
+    pub fn resolve_error(&self) {
+        self.config_loader.resolve_error();
+    }
+
     pub fn get(&self) -> &T {
         &self.config
     }
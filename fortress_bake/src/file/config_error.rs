@@ -0,0 +1,78 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    path::PathBuf,
+    rc::Rc,
+};
+
+// A config load failure, with the parser's line and column when it has them.
+#[derive(Clone, Debug)]
+pub struct ConfigError {
+    pub message: String,
+    pub line_column: Option<(usize, usize)>,
+}
+
+impl From<String> for ConfigError {
+    fn from(message: String) -> ConfigError {
+        ConfigError {
+            message,
+            line_column: None,
+        }
+    }
+}
+
+impl From<ConfigError> for String {
+    fn from(error: ConfigError) -> String {
+        error.to_string()
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line_column {
+            Some((line, column)) => write!(f, "{}:{}: {}", line, column, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ReloadError {
+    pub path: PathBuf,
+    pub error: ConfigError,
+}
+
+// Shared by the ConfigWatcher and every loader it hands out, so failures from anywhere end up in one place.
+#[derive(Clone, Default)]
+pub struct ReloadErrors {
+    errors: Rc<RefCell<Vec<ReloadError>>>,
+}
+
+impl ReloadErrors {
+    // Replaces any older error for the same file.
+    pub fn report(&self, path: PathBuf, error: ConfigError) {
+        println!("Error reloading {:?}: {}", path, error);
+        let mut errors = self.errors.borrow_mut();
+        errors.retain(|existing| existing.path != path);
+        errors.push(ReloadError {
+            path,
+            error,
+        });
+    }
+
+    pub fn resolve(&self, path: &PathBuf) {
+        self.errors.borrow_mut().retain(|existing| existing.path != *path);
+    }
+
+    pub fn clear(&self) {
+        self.errors.borrow_mut().clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.borrow().is_empty()
+    }
+
+    pub fn errors(&self) -> Vec<ReloadError> {
+        self.errors.borrow().clone()
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        Config,
+        ConfigError,
+        ReloadError,
+    },
+};
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+};
+
+// Loads configs the way the game does, but keeps going after a failure so every problem is reported in one pass.
+#[derive(Default)]
+pub struct ConfigLint {
+    checked: HashSet<PathBuf>,
+    issues: Vec<ReloadError>,
+}
+
+impl ConfigLint {
+    pub fn new() -> ConfigLint {
+        Self::default()
+    }
+
+    // Checks res/config/<name> alone and merged under every profile and the user's dir.
+    pub fn check_config<T: Config>(&mut self, config_file_name: &str) -> Option<T> {
+        let base = file::util::config_path(config_file_name);
+        let loaded = self.check_layers::<T>(&base, file::util::config_layer_paths_for(config_file_name, None));
+        for profile in file::util::config_profiles() {
+            let layers = file::util::config_layer_paths_for(config_file_name, Some(&profile));
+            if let Some(profile_layer) = layers.get(1).cloned() {
+                self.check_layers::<T>(&profile_layer, layers);
+            }
+        }
+        loaded
+    }
+
+    pub fn check_file<T: Config>(&mut self, path: &PathBuf) -> Option<T> {
+        self.check_layers::<T>(path, vec!(path.clone()))
+    }
+
+    // For files checked some other way than loading them as configs.
+    pub fn mark_checked(&mut self, path: PathBuf) {
+        self.checked.insert(path);
+    }
+
+    // For failures that come from combining files, like map fragments that don't fit together.
+    pub fn report(&mut self, path: PathBuf, error: ConfigError) {
+        self.issues.push(ReloadError {
+            path,
+            error,
+        });
+    }
+
+    // Every .conf in res/config and its profiles should have been checked by now. Anything left over is a typo
+    // or a config the game no longer reads.
+    pub fn check_unused_configs(&mut self) -> StatusOr<()> {
+        let mut dirs = vec!(file::util::resource_base().join("config"));
+        dirs.extend(file::util::config_profiles().into_iter()
+            .map(|profile| file::util::config_profiles_dir().join(profile)));
+        if let Some(user_dir) = file::util::config_override_dir() {
+            dirs.push(user_dir);
+        }
+
+        for dir in dirs {
+            for base_name in file::util::files_in_dir_ending_with(&dir, ".conf")? {
+                let path = dir.join(base_name);
+                if !self.checked.contains(&path) {
+                    self.report(path, ConfigError::from(String::from("No config with this name is loaded by the game")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn num_checked(&self) -> usize {
+        self.checked.len()
+    }
+
+    pub fn issues(&self) -> &[ReloadError] {
+        &self.issues
+    }
+
+    // Prints every issue and returns an error if there were any.
+    pub fn finish(&self) -> StatusOr<()> {
+        for issue in self.issues.iter() {
+            println!("{}: {}", issue.path.display(), issue.error);
+        }
+        println!("Checked {} files, found {} issues", self.num_checked(), self.issues.len());
+
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("{} resource files failed lint", self.issues.len()))
+        }
+    }
+
+    fn check_layers<T: Config>(&mut self, path: &PathBuf, layers: Vec<PathBuf>) -> Option<T> {
+        self.checked.extend(layers.iter().cloned());
+        match T::from_layers(&layers) {
+            Ok(config) => Some(config),
+            Err(error) => {
+                self.report(path.clone(), error);
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::file::ConfigError;
+use std::fmt::Display;
+
+// Semantic checks that run after a config deserializes, on the first load and on every hot reload.
+// Configs with nothing to check can #[derive(Validate)] from fortress_derive.
+pub trait Validate {
+    fn validate(&self, _validator: &mut ConfigValidator) {}
+}
+
+#[derive(Clone, Debug)]
+pub struct ConfigViolation {
+    pub field: String,
+    pub message: String,
+}
+
+// Collects every violation instead of stopping at the first, tracking the field path as it descends.
+#[derive(Default)]
+pub struct ConfigValidator {
+    path: Vec<String>,
+    violations: Vec<ConfigViolation>,
+}
+
+impl ConfigValidator {
+    pub fn new() -> ConfigValidator {
+        Self::default()
+    }
+
+    pub fn nested<T: Validate>(&mut self, field: impl Display, value: &T) {
+        self.path.push(field.to_string());
+        value.validate(self);
+        self.path.pop();
+    }
+
+    pub fn require(&mut self, field: impl Display, condition: bool, message: impl Display) {
+        if !condition {
+            self.violations.push(ConfigViolation {
+                field: self.field_path(field),
+                message: message.to_string(),
+            });
+        }
+    }
+
+    pub fn at_least<N: PartialOrd + Display>(&mut self, field: impl Display, value: N, min: N) {
+        let message = format!("must be at least {} but is {}", min, value);
+        self.require(field, value >= min, message);
+    }
+
+    pub fn positive<N: PartialOrd + Display + Default>(&mut self, field: impl Display, value: N) {
+        let message = format!("must be greater than 0 but is {}", value);
+        self.require(field, value > N::default(), message);
+    }
+
+    pub fn in_range<N: PartialOrd + Display>(&mut self, field: impl Display, value: N, min: N, max: N) {
+        let message = format!("must be in [{}, {}] but is {}", min, max, value);
+        self.require(field, value >= min && value <= max, message);
+    }
+
+    pub fn violations(&self) -> &[ConfigViolation] {
+        &self.violations
+    }
+
+    pub fn finish(self) -> Result<(), ConfigError> {
+        if self.violations.is_empty() {
+            return Ok(());
+        }
+
+        let listed: Vec<String> = self.violations.iter()
+            .map(|violation| format!("{}: {}", violation.field, violation.message))
+            .collect();
+        Err(ConfigError::from(format!("{} invalid field(s): {}", self.violations.len(), listed.join("; "))))
+    }
+
+    fn field_path(&self, field: impl Display) -> String {
+        let field = field.to_string();
+        if self.path.is_empty() {
+            return field;
+        }
+
+        let mut full_path = self.path.join(".");
+        if !field.starts_with('[') {
+            full_path.push('.');
+        }
+        full_path.push_str(&field);
+        full_path
+    }
+}
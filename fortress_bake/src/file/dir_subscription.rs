@@ -0,0 +1,86 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        ConfigError,
+        ReloadErrors,
+    },
+};
+use std::{
+    cell::RefCell,
+    path::PathBuf,
+    rc::Rc,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirEvent {
+    Added(PathBuf),
+    Changed(PathBuf),
+    Removed(PathBuf),
+    // The watcher lost track of events. List the directory again.
+    Rescan,
+}
+
+// Events for files in one directory with a given extension, queued until the owner takes them.
+pub struct DirSubscription {
+    dir: PathBuf,
+    extension: String,
+    events: Rc<RefCell<Vec<DirEvent>>>,
+    reload_errors: ReloadErrors,
+}
+
+impl DirSubscription {
+    pub fn new(dir: PathBuf, extension: &str, reload_errors: ReloadErrors) -> DirSubscription {
+        DirSubscription {
+            dir,
+            extension: String::from(extension),
+            events: Rc::new(RefCell::new(Vec::new())),
+            reload_errors,
+        }
+    }
+
+    // Shares the event queue with the ConfigWatcher.
+    pub fn share(&self) -> DirSubscription {
+        DirSubscription {
+            dir: self.dir.clone(),
+            extension: self.extension.clone(),
+            events: Rc::clone(&self.events),
+            reload_errors: self.reload_errors.clone(),
+        }
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    pub fn matches(&self, path: &PathBuf) -> bool {
+        path.parent() == Some(self.dir.as_path())
+            && path.extension().map_or(false, |extension| extension == self.extension.as_str())
+    }
+
+    // Sorted so systems that combine files get the same result on every platform.
+    pub fn files(&self) -> StatusOr<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = file::util::files_in_dir_ending_with(&self.dir, &format!(".{}", self.extension))?
+            .into_iter()
+            .map(|base_name| self.dir.join(base_name))
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    pub fn push(&self, event: DirEvent) {
+        self.events.borrow_mut().push(event);
+    }
+
+    pub fn take_events(&self) -> Vec<DirEvent> {
+        self.events.replace(Vec::new())
+    }
+
+    pub fn report_error(&self, path: PathBuf, error: ConfigError) {
+        self.reload_errors.report(path, error);
+    }
+
+    pub fn resolve_error(&self, path: &PathBuf) {
+        self.reload_errors.resolve(path);
+    }
+}
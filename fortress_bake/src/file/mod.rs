@@ -1,9 +1,29 @@
+pub mod asset_references;
 pub mod config;
+pub mod config_error;
+pub mod config_lint;
+pub mod config_validation;
+pub mod dir_subscription;
 pub mod mmap_file;
+pub mod resource_archive;
+pub mod resource_bytes;
+pub mod ron_layers;
 pub mod util;
 
+pub use self::asset_references::AssetReferences;
 pub use self::config::Config;
 pub use self::config::ConfigLoader;
 pub use self::config::ConfigWatcher;
 pub use self::config::SimpleConfigManager;
+pub use self::config_error::ConfigError;
+pub use self::config_lint::ConfigLint;
+pub use self::config_error::ReloadError;
+pub use self::config_error::ReloadErrors;
+pub use self::config_validation::ConfigValidator;
+pub use self::config_validation::ConfigViolation;
+pub use self::config_validation::Validate;
+pub use self::dir_subscription::DirEvent;
+pub use self::dir_subscription::DirSubscription;
 pub use self::mmap_file::MmapFile;
+pub use self::resource_archive::ResourceArchive;
+pub use self::resource_bytes::ResourceBytes;
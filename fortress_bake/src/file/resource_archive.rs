@@ -0,0 +1,198 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        MmapFile,
+        ResourceBytes,
+    },
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{
+        BufWriter,
+        Seek,
+        SeekFrom,
+        Write,
+    },
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+};
+
+const MAGIC: &[u8; 8] = b"FORTPAK1";
+// Magic, then the index offset and length as little endian u64s.
+const HEADER_LEN: usize = 24;
+
+#[derive(Serialize, Deserialize)]
+struct ArchiveEntry {
+    path: String,
+    offset: u64,
+    len: u64,
+}
+
+// Every file under res/ concatenated into one file, with a RON index at the end.
+// Entries are keyed by their path relative to res/, with '/' separators.
+pub struct ResourceArchive {
+    file: ResourceBytes,
+    entries: HashMap<String, (usize, usize)>,
+}
+
+impl ResourceArchive {
+    pub fn open(path: &PathBuf) -> StatusOr<ResourceArchive> {
+        Self::from_bytes(ResourceBytes::Mapped(MmapFile::read(path)?))
+            .map_err(|e| format!("{} in {:?}", e, path))
+    }
+
+    // For an archive compiled into the executable.
+    pub fn from_static(bytes: &'static [u8]) -> StatusOr<ResourceArchive> {
+        Self::from_bytes(ResourceBytes::Archived(bytes))
+    }
+
+    fn from_bytes(file: ResourceBytes) -> StatusOr<ResourceArchive> {
+        let bytes = file.bytes();
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(String::from("Not a resource archive"));
+        }
+
+        let index_offset = Self::read_u64(&bytes[8..16]) as usize;
+        let index_len = Self::read_u64(&bytes[16..24]) as usize;
+        let index_bytes = bytes.get(index_offset..index_offset + index_len)
+            .ok_or(String::from("Resource archive index out of bounds"))?;
+        let index: Vec<ArchiveEntry> = ron::de::from_bytes(index_bytes)
+            .map_err(|e| format!("Couldn't parse resource archive index: {}", e))?;
+
+        let mut entries = HashMap::with_capacity(index.len());
+        for entry in index {
+            let (offset, len) = (entry.offset as usize, entry.len as usize);
+            if offset + len > index_offset {
+                return Err(format!("Resource archive entry {} out of bounds", entry.path));
+            }
+            entries.insert(entry.path, (offset, len));
+        }
+
+        Ok(ResourceArchive {
+            file,
+            entries,
+        })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        let (offset, len) = *self.entries.get(key)?;
+        Some(&self.file.bytes()[offset..offset + len])
+    }
+
+    pub fn is_dir(&self, key: &str) -> bool {
+        let prefix = Self::dir_prefix(key);
+        self.entries.keys().any(|entry| entry.starts_with(&prefix))
+    }
+
+    // Names of the files and directories directly inside key, sorted.
+    pub fn list_dir(&self, key: &str) -> Vec<String> {
+        let prefix = Self::dir_prefix(key);
+        let mut names: Vec<String> = self.entries.keys()
+            .filter_map(|entry| entry.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split('/').next())
+            .map(String::from)
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn key(relative_path: &Path) -> String {
+        relative_path.components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect::<Vec<&str>>()
+            .join("/")
+    }
+
+    // Packs every file under res_dir into an archive at out_path. Returns the number of files packed.
+    pub fn pack(res_dir: &Path, out_path: &Path) -> StatusOr<usize> {
+        let files = Self::input_files(res_dir)?;
+
+        let out_file = File::create(out_path)
+            .map_err(|e| format!("Couldn't create resource archive {:?}: {}", out_path, e))?;
+        let mut writer = BufWriter::new(out_file);
+        let write_error = |e: std::io::Error| format!("Couldn't write resource archive {:?}: {}", out_path, e);
+
+        writer.write_all(&[0; HEADER_LEN]).map_err(write_error)?;
+        let mut offset = HEADER_LEN as u64;
+        let mut index = Vec::with_capacity(files.len());
+        for path in files.iter() {
+            let bytes = std::fs::read(path)
+                .map_err(|e| format!("Couldn't read {:?}: {}", path, e))?;
+            writer.write_all(&bytes).map_err(write_error)?;
+
+            let relative = path.strip_prefix(res_dir)
+                .map_err(|e| format!("{:?} isn't in {:?}: {}", path, res_dir, e))?;
+            index.push(ArchiveEntry {
+                path: Self::key(relative),
+                offset,
+                len: bytes.len() as u64,
+            });
+            offset += bytes.len() as u64;
+        }
+
+        let index_string = ron::ser::to_string(&index)
+            .map_err(|e| format!("Couldn't serialize resource archive index: {:?}", e))?;
+        writer.write_all(index_string.as_bytes()).map_err(write_error)?;
+
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&offset.to_le_bytes());
+        header.extend_from_slice(&(index_string.len() as u64).to_le_bytes());
+        writer.seek(SeekFrom::Start(0)).map_err(write_error)?;
+        writer.write_all(&header).map_err(write_error)?;
+        writer.flush().map_err(write_error)?;
+
+        Ok(index.len())
+    }
+
+    // Every file pack would put in the archive, sorted.
+    pub fn input_files(res_dir: &Path) -> StatusOr<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        Self::collect_files(res_dir, &mut files)?;
+        files.sort();
+        Ok(files)
+    }
+
+    fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> StatusOr<()> {
+        for entry in dir.read_dir().map_err(|e| format!("Couldn't read dir {:?}: {}", dir, e))? {
+            let path = entry.map_err(|e| format!("Couldn't read entry in {:?}: {}", dir, e))?.path();
+            // Skips broken links, which the game couldn't read either.
+            if path.is_dir() {
+                Self::collect_files(&path, files)?;
+            } else if path.is_file() {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    fn dir_prefix(key: &str) -> String {
+        if key.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", key)
+        }
+    }
+
+    fn read_u64(bytes: &[u8]) -> u64 {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(bytes);
+        u64::from_le_bytes(buf)
+    }
+}
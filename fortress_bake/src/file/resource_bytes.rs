@@ -0,0 +1,16 @@
+use crate::file::MmapFile;
+
+// A file's contents, either mapped from a loose file or borrowed from an archive that lives for the whole run.
+pub enum ResourceBytes {
+    Mapped(MmapFile),
+    Archived(&'static [u8]),
+}
+
+impl ResourceBytes {
+    pub fn bytes(&self) -> &[u8] {
+        match self {
+            ResourceBytes::Mapped(file) => file.bytes(),
+            ResourceBytes::Archived(bytes) => bytes,
+        }
+    }
+}
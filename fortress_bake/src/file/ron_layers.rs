@@ -0,0 +1,580 @@
+use crate::file::{
+    self,
+    ConfigError,
+};
+use std::path::PathBuf;
+
+// Just enough of RON's structure to merge config layers field by field. Named fields and map
+// entries are merged recursively; everything else (numbers, enums, tuples, lists) is replaced whole.
+// Going through ron::Value instead would drop enum variant names.
+enum RonNode {
+    Struct {
+        name: Option<String>,
+        fields: Vec<(String, RonNode)>,
+    },
+    Map(Vec<(String, RonNode)>),
+    Raw(String),
+}
+
+impl RonNode {
+    fn merge(self, over: RonNode) -> RonNode {
+        match (self, over) {
+            (RonNode::Struct { name, mut fields }, RonNode::Struct { name: over_name, fields: over_fields })
+                if over_name.is_none() || over_name == name => {
+                Self::merge_entries(&mut fields, over_fields);
+                RonNode::Struct { name, fields }
+            },
+            (RonNode::Map(mut entries), RonNode::Map(over_entries)) => {
+                Self::merge_entries(&mut entries, over_entries);
+                RonNode::Map(entries)
+            },
+            (_, over) => over,
+        }
+    }
+
+    fn merge_entries(entries: &mut Vec<(String, RonNode)>, over_entries: Vec<(String, RonNode)>) {
+        for (key, value) in over_entries {
+            match entries.iter().position(|(existing, _)| *existing == key) {
+                Some(i) => {
+                    let base = std::mem::replace(&mut entries[i].1, RonNode::Raw(String::new()));
+                    entries[i].1 = base.merge(value);
+                },
+                None => entries.push((key, value)),
+            }
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            RonNode::Raw(text) => out.push_str(text),
+            RonNode::Struct { name, fields } => {
+                if let Some(name) = name {
+                    out.push_str(name);
+                }
+                out.push('(');
+                Self::write_entries(fields, out);
+                out.push(')');
+            },
+            RonNode::Map(entries) => {
+                out.push('{');
+                Self::write_entries(entries, out);
+                out.push('}');
+            },
+        }
+    }
+
+    fn write_entries(entries: &[(String, RonNode)], out: &mut String) {
+        for (key, value) in entries.iter() {
+            out.push_str(key);
+            out.push_str(": ");
+            value.write(out);
+            out.push_str(",\n");
+        }
+    }
+}
+
+struct RonParser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RonParser<'a> {
+    // Returns any #![enable(...)] extension lines along with the document.
+    fn parse_document(text: &'a str) -> Result<(String, RonNode), ConfigError> {
+        let mut parser = RonParser {
+            text,
+            bytes: text.as_bytes(),
+            pos: 0,
+        };
+
+        let mut extensions = String::new();
+        parser.skip_ws();
+        while parser.bytes[parser.pos..].starts_with(b"#!") {
+            let start = parser.pos;
+            while parser.peek().map_or(false, |c| c != b']') {
+                parser.pos += 1;
+            }
+            parser.expect(b']')?;
+            extensions.push_str(&text[start..parser.pos]);
+            extensions.push('\n');
+            parser.skip_ws();
+        }
+
+        let node = parser.value()?;
+        parser.skip_ws();
+        if parser.pos < parser.bytes.len() {
+            return Err(parser.error("unexpected trailing characters"));
+        }
+        Ok((extensions, node))
+    }
+
+    fn value(&mut self) -> Result<RonNode, ConfigError> {
+        self.skip_ws();
+        let start = self.pos;
+        match self.peek() {
+            Some(b'(') => self.parens(None, start),
+            Some(b'{') => self.map(),
+            Some(c) if Self::is_ident_start(c) && !self.at_raw_string() => {
+                let name = self.identifier();
+                self.skip_ws();
+                if self.peek() == Some(b'(') {
+                    self.parens(Some(name), start)
+                } else {
+                    Ok(RonNode::Raw(name))
+                }
+            },
+            _ => self.raw(start),
+        }
+    }
+
+    fn parens(&mut self, name: Option<String>, start: usize) -> Result<RonNode, ConfigError> {
+        if !self.looks_like_struct() {
+            // Tuples, tuple structs and newtype variants.
+            self.pos = start;
+            return self.raw(start);
+        }
+
+        self.pos += 1;
+        let fields = self.entries(b')', |parser| {
+            parser.skip_ws();
+            if !parser.peek().map_or(false, Self::is_ident_start) {
+                return Err(parser.error("expected a field name"));
+            }
+            Ok(parser.identifier())
+        })?;
+        Ok(RonNode::Struct { name, fields })
+    }
+
+    fn map(&mut self) -> Result<RonNode, ConfigError> {
+        self.pos += 1;
+        let entries = self.entries(b'}', |parser| {
+            let mut key = String::new();
+            parser.value()?.write(&mut key);
+            Ok(key)
+        })?;
+        Ok(RonNode::Map(entries))
+    }
+
+    fn entries(&mut self,
+               close: u8,
+               key: fn(&mut Self) -> Result<String, ConfigError>) -> Result<Vec<(String, RonNode)>, ConfigError> {
+        let mut entries = Vec::new();
+        loop {
+            self.skip_ws();
+            match self.peek() {
+                None => return Err(self.error("unexpected end of file")),
+                Some(c) if c == close => {
+                    self.pos += 1;
+                    return Ok(entries);
+                },
+                _ => {},
+            }
+
+            let key = key(self)?;
+            self.skip_ws();
+            self.expect(b':')?;
+            let value = self.value()?;
+            entries.push((key, value));
+
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(c) if c == close => {},
+                _ => return Err(self.error(&format!("expected ',' or '{}'", close as char))),
+            }
+        }
+    }
+
+    // Copies a value verbatim, up to the next top level ',', ':' or closing bracket.
+    fn raw(&mut self, start: usize) -> Result<RonNode, ConfigError> {
+        let mut depth = 0;
+        let mut end = start;
+        while let Some(c) = self.peek() {
+            match c {
+                b'(' | b'[' | b'{' => {
+                    depth += 1;
+                    self.pos += 1;
+                },
+                b')' | b']' | b'}' => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                    self.pos += 1;
+                },
+                b',' | b':' if depth == 0 => break,
+                b'"' => self.skip_string()?,
+                b'\'' => self.skip_char_literal()?,
+                b'r' if self.at_raw_string() => self.skip_raw_string()?,
+                b'/' if self.at_comment() => {
+                    self.skip_ws();
+                    continue;
+                },
+                c if c.is_ascii_whitespace() => {
+                    self.pos += 1;
+                    continue;
+                },
+                _ => self.pos += 1,
+            }
+            end = self.pos;
+        }
+
+        if end == start {
+            return Err(self.error("expected a value"));
+        }
+        Ok(RonNode::Raw(String::from(&self.text[start..end])))
+    }
+
+    fn looks_like_struct(&mut self) -> bool {
+        let saved = self.pos;
+        self.pos += 1;
+        self.skip_ws();
+        let is_struct = if self.peek().map_or(false, Self::is_ident_start) {
+            self.identifier();
+            self.skip_ws();
+            self.peek() == Some(b':')
+        } else {
+            false
+        };
+        self.pos = saved;
+        is_struct
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().map_or(false, |c| c.is_ascii_alphanumeric() || c == b'_') {
+            self.pos += 1;
+        }
+        String::from(&self.text[start..self.pos])
+    }
+
+    fn skip_ws(&mut self) {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_whitespace() => self.pos += 1,
+                Some(b'/') if self.bytes.get(self.pos + 1) == Some(&b'/') => {
+                    while self.peek().map_or(false, |c| c != b'\n') {
+                        self.pos += 1;
+                    }
+                },
+                Some(b'/') if self.bytes.get(self.pos + 1) == Some(&b'*') => {
+                    self.pos += 2;
+                    while self.pos < self.bytes.len() && !self.bytes[self.pos..].starts_with(b"*/") {
+                        self.pos += 1;
+                    }
+                    self.pos = (self.pos + 2).min(self.bytes.len());
+                },
+                _ => return,
+            }
+        }
+    }
+
+    fn skip_string(&mut self) -> Result<(), ConfigError> {
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'\\') => self.pos += 2,
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(());
+                },
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_char_literal(&mut self) -> Result<(), ConfigError> {
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated character literal")),
+                Some(b'\\') => self.pos += 2,
+                Some(b'\'') => {
+                    self.pos += 1;
+                    return Ok(());
+                },
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_raw_string(&mut self) -> Result<(), ConfigError> {
+        self.pos += 1;
+        let mut hashes = 0;
+        while self.peek() == Some(b'#') {
+            hashes += 1;
+            self.pos += 1;
+        }
+        self.expect(b'"')?;
+
+        let mut terminator = vec!(b'"');
+        terminator.extend(std::iter::repeat(b'#').take(hashes));
+        while self.pos < self.bytes.len() {
+            if self.bytes[self.pos..].starts_with(&terminator) {
+                self.pos += terminator.len();
+                return Ok(());
+            }
+            self.pos += 1;
+        }
+        Err(self.error("unterminated raw string"))
+    }
+
+    fn at_raw_string(&self) -> bool {
+        self.peek() == Some(b'r') && matches!(self.bytes.get(self.pos + 1), Some(b'"') | Some(b'#'))
+    }
+
+    fn at_comment(&self) -> bool {
+        matches!(self.bytes.get(self.pos + 1), Some(b'/') | Some(b'*'))
+    }
+
+    fn is_ident_start(c: u8) -> bool {
+        c.is_ascii_alphabetic() || c == b'_'
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), ConfigError> {
+        if self.peek() != Some(c) {
+            return Err(self.error(&format!("expected '{}'", c as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn error(&self, message: &str) -> ConfigError {
+        let consumed = &self.bytes[..self.pos.min(self.bytes.len())];
+        let line = consumed.iter().filter(|c| **c == b'\n').count() + 1;
+        let col = consumed.iter().rev().take_while(|c| **c != b'\n').count() + 1;
+        ConfigError {
+            message: String::from(message),
+            line_column: Some((line, col)),
+        }
+    }
+}
+
+// Merges the RON files in order, later layers overriding earlier ones, and returns the merged text.
+pub fn merge_layers(layers: &[PathBuf]) -> Result<String, ConfigError> {
+    let mut texts = Vec::with_capacity(layers.len());
+    for layer in layers.iter() {
+        texts.push((layer, file::util::slurp_file(layer)?));
+    }
+    merge_texts(texts.iter().map(|(layer, text)| (format!("{:?}", layer), text.as_str())))
+}
+
+// Like merge_layers, with each layer's text given along with a name for errors.
+fn merge_texts<'a>(layers: impl Iterator<Item = (String, &'a str)>) -> Result<String, ConfigError> {
+    let mut merged: Option<(String, RonNode)> = None;
+    for (name, text) in layers {
+        let (extensions, node) = RonParser::parse_document(text)
+            .map_err(|e| ConfigError {
+                message: format!("Couldn't parse config layer {}: {}", name, e.message),
+                line_column: e.line_column,
+            })?;
+
+        merged = Some(match merged {
+            None => (extensions, node),
+            Some((base_extensions, base)) => (base_extensions, base.merge(node)),
+        });
+    }
+
+    let (extensions, node) = merged.ok_or_else(|| ConfigError::from(String::from("No config layers to merge")))?;
+    let mut out = extensions;
+    node.write(&mut out);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn merge(layers: &[&str]) -> String {
+        merge_texts(layers.iter().enumerate().map(|(i, text)| (format!("layer {}", i), *text)))
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn merge_into<T: serde::de::DeserializeOwned>(layers: &[&str]) -> T {
+        let merged = merge(layers);
+        ron::de::from_str(&merged).unwrap_or_else(|e| panic!("{}\n{}", e, merged))
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Inner {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Nested {
+        a: i32,
+        inner: Inner,
+    }
+
+    #[test]
+    fn nested_struct_fields_merge() {
+        let merged: Nested = merge_into(&[
+            "Nested(a: 1, inner: (x: 1, y: 2))",
+            "(inner: (y: 3))",
+        ]);
+        assert_eq!(merged, Nested { a: 1, inner: Inner { x: 1, y: 3 } });
+    }
+
+    #[test]
+    fn struct_with_another_name_replaces() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Shape {
+            Square { side: i32 },
+            Rect { width: i32, height: i32 },
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Shapes {
+            shape: Shape,
+        }
+
+        let merged: Shapes = merge_into(&[
+            "(shape: Rect(width: 1, height: 2))",
+            "(shape: Square(side: 3))",
+        ]);
+        assert_eq!(merged.shape, Shape::Square { side: 3 });
+
+        let merged: Shapes = merge_into(&[
+            "(shape: Rect(width: 1, height: 2))",
+            "(shape: Rect(height: 5))",
+        ]);
+        assert_eq!(merged.shape, Shape::Rect { width: 1, height: 5 });
+    }
+
+    #[test]
+    fn map_entries_merge() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Maps {
+            numbers: HashMap<String, i32>,
+            inners: HashMap<String, Inner>,
+        }
+
+        let merged: Maps = merge_into(&[
+            r#"(numbers: {"a": 1, "b": 2}, inners: {"p": (x: 1, y: 2), "q": (x: 3, y: 4)})"#,
+            r#"(numbers: {"b": 5, "c": 6}, inners: {"q": (y: 7)})"#,
+        ]);
+        assert_eq!(merged.numbers, [("a", 1), ("b", 5), ("c", 6)].iter().map(|(k, v)| (String::from(*k), *v)).collect());
+        assert_eq!(merged.inners["p"], Inner { x: 1, y: 2 });
+        assert_eq!(merged.inners["q"], Inner { x: 3, y: 7 });
+    }
+
+    #[test]
+    fn enum_keys_and_values() {
+        #[derive(Debug, PartialEq, Eq, Hash, Deserialize)]
+        enum Key {
+            First,
+            Second,
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Mode {
+            Windowed,
+            Fullscreen,
+            Sized(i32, i32),
+        }
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Enums {
+            modes: HashMap<Key, Mode>,
+        }
+
+        let merged: Enums = merge_into(&[
+            "(modes: {First: Windowed, Second: Sized(1, 2)})",
+            "(modes: {First: Sized(3, 4), Second: Fullscreen})",
+        ]);
+        assert_eq!(merged.modes[&Key::First], Mode::Sized(3, 4));
+        assert_eq!(merged.modes[&Key::Second], Mode::Fullscreen);
+    }
+
+    #[test]
+    fn options_and_tuples_replace_whole() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Options {
+            seed: Option<u64>,
+            size: (i32, i32),
+            list: Vec<i32>,
+        }
+
+        let merged: Options = merge_into(&[
+            "(seed: None, size: (1, 2), list: [1, 2, 3])",
+            "(seed: Some(5), size: (3, 4), list: [4])",
+        ]);
+        assert_eq!(merged, Options { seed: Some(5), size: (3, 4), list: vec!(4) });
+
+        let merged: Options = merge_into(&[
+            "(seed: Some(5), size: (1, 2), list: [])",
+            "(size: (3, 4))",
+        ]);
+        assert_eq!(merged, Options { seed: Some(5), size: (3, 4), list: Vec::new() });
+    }
+
+    #[test]
+    fn comments_are_skipped() {
+        let merged: Nested = merge_into(&[
+            "// A leading comment, with: punctuation\nNested(\n    a: 1, // trailing (comment)\n    /* block: {comment} */ inner: (x: 1, y: 2),\n)",
+            "(\n    // Only y changes.\n    inner: (y: /* inline */ 3),\n)",
+        ]);
+        assert_eq!(merged, Nested { a: 1, inner: Inner { x: 1, y: 3 } });
+    }
+
+    #[test]
+    fn strings_keep_their_contents() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Strings {
+            plain: String,
+            raw: String,
+            chars: (char, char),
+        }
+
+        let merged: Strings = merge_into(&[
+            r#"(plain: "a, b: (c)", raw: "", chars: ('a', 'b'))"#,
+            r###"(raw: r#"quoted "x", y: {z}"#, chars: (',', ')'))"###,
+        ]);
+        assert_eq!(merged, Strings {
+            plain: String::from("a, b: (c)"),
+            raw: String::from(r#"quoted "x", y: {z}"#),
+            chars: (',', ')'),
+        });
+    }
+
+    #[test]
+    fn extensions_come_from_the_base_layer() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Implicit {
+            seed: Option<u64>,
+            other: i32,
+        }
+
+        let merged_text = merge(&[
+            "#![enable(implicit_some)]\n(seed: 1, other: 2)",
+            "#![enable(implicit_some)]\n(seed: 3)",
+        ]);
+        assert_eq!(merged_text.matches("#![enable(implicit_some)]").count(), 1);
+        let merged: Implicit = ron::de::from_str(&merged_text).unwrap();
+        assert_eq!(merged, Implicit { seed: Some(3), other: 2 });
+    }
+
+    #[test]
+    fn errors_name_the_layer_and_position() {
+        let layers = ["(a: 1)", "(\n    a: 1\n    b: 2)"];
+        let e = merge_texts(layers.iter().enumerate().map(|(i, text)| (format!("layer {}", i), *text)))
+            .err()
+            .expect("Missing comma should fail");
+        assert!(e.message.contains("layer 1"), "{}", e.message);
+        assert_eq!(e.line_column.map(|(line, _)| line), Some(3));
+    }
+
+    // Why the layers aren't merged as ron::Value: the variant names are gone by the time we'd merge.
+    #[test]
+    fn ron_value_drops_enum_variant_names() {
+        assert_eq!(ron::de::from_str::<ron::Value>("Windowed").unwrap(), ron::Value::Unit);
+        assert_eq!(
+            ron::de::from_str::<ron::Value>("Sized(1, 2)").unwrap(),
+            ron::de::from_str::<ron::Value>("(1, 2)").unwrap());
+    }
+}
@@ -1,13 +1,13 @@
 use crate::{
     app::StatusOr,
-    file::MmapFile,
+    file::{
+        MmapFile,
+        ResourceArchive,
+        ResourceBytes,
+    },
 };
 use std::{
-    ffi::OsString,
-    fs::{
-        File,
-        self
-    },
+    fs::File,
     io::{
         BufReader,
         Read,
@@ -16,60 +16,284 @@ use std::{
         Path,
         PathBuf
     },
+    sync::{
+        OnceLock,
+        RwLock,
+    },
 };
 
-lazy_static! {
-  static ref RESOURCE_BASE: PathBuf = try_find_resource_base().unwrap();
+pub const RESOURCE_DIR_ENV_VAR: &str = "FORTRESS_RESOURCE_DIR";
+pub const CONFIG_DIR_ENV_VAR: &str = "FORTRESS_CONFIG_DIR";
+pub const RESOURCE_ARCHIVE_NAME: &str = "res.pak";
+// Stands in for the resource base when the only resources are the ones compiled into the executable.
+pub const EMBEDDED_RESOURCE_BASE: &str = "<embedded res>";
+
+struct MountedArchive {
+    archive: ResourceArchive,
+    // Set when the archive sits under a real res/ folder, whose files take precedence one by one.
+    loose_files_override: bool,
 }
 
+// Set once at startup by init_resource_base, before anything loads a resource.
+static RESOURCE_BASE: OnceLock<PathBuf> = OnceLock::new();
+// Mounted when the resource base is an archive, or when resources are embedded in the executable.
+static RESOURCE_ARCHIVE: OnceLock<MountedArchive> = OnceLock::new();
+static EMBEDDED_RESOURCES: OnceLock<&'static [u8]> = OnceLock::new();
+static CONFIG_OVERRIDE_DIR: OnceLock<PathBuf> = OnceLock::new();
+// Unlike the paths above, the profile can change at runtime.
+static CONFIG_PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+// Binaries call init_resource_base up front to report a missing folder. This is the fallback for everything else.
 pub fn resource_base() -> PathBuf {
-    RESOURCE_BASE.to_path_buf()
+    if let Some(path) = RESOURCE_BASE.get() {
+        return path.clone();
+    }
+    match init_resource_base(None) {
+        Ok(path) => path,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+// Finds the resource folder or archive and fixes it for the rest of the run. Call before anything loads a resource.
+// Paths under an archive's path are read out of the archive, so the rest of the game can't tell the difference.
+pub fn init_resource_base(command_line: Option<&Path>) -> StatusOr<PathBuf> {
+    let embedded = EMBEDDED_RESOURCES.get().copied();
+    let (path, archive) = match (find_resource_base(command_line), embedded) {
+        (Ok(path), _) if path.is_file() => {
+            let archive = ResourceArchive::open(&path)?;
+            (path, Some((archive, false)))
+        },
+        (Ok(path), Some(bytes)) => (path, Some((ResourceArchive::from_static(bytes)?, true))),
+        (Ok(path), None) => (path, None),
+        // Only fall back to the embedded resources if nothing was asked for explicitly.
+        (Err(_), Some(bytes)) if command_line.is_none() && std::env::var_os(RESOURCE_DIR_ENV_VAR).is_none() =>
+            (PathBuf::from(EMBEDDED_RESOURCE_BASE), Some((ResourceArchive::from_static(bytes)?, false))),
+        (Err(e), _) => return Err(e),
+    };
+
+    if let Some((archive, loose_files_override)) = archive {
+        if !archive.is_dir("config") {
+            return Err(format!("No config folder in {:?}", path));
+        }
+        RESOURCE_ARCHIVE.set(MountedArchive { archive, loose_files_override })
+            .map_err(|_| format!("Resource archive already mounted, ignoring {:?}", path))?;
+    }
+    RESOURCE_BASE.set(path.clone())
+        .map_err(|path| format!("Resource base already set, ignoring {:?}", path))?;
+    Ok(path)
+}
+
+// Registers resources compiled into the executable. They're used when no res/ folder or archive can be found,
+// and fill in for any file missing from a res/ folder that is found. Call before init_resource_base.
+pub fn embed_resources(bytes: &'static [u8]) -> StatusOr<()> {
+    EMBEDDED_RESOURCES.set(bytes)
+        .map_err(|_| String::from("Embedded resources already set"))
+}
+
+// Tries the command line, then $FORTRESS_RESOURCE_DIR, then a res/ folder or res.pak archive next to the
+// executable or the working directory or in any of their parents. A loose folder wins over an archive beside it.
+pub fn find_resource_base(command_line: Option<&Path>) -> StatusOr<PathBuf> {
+    if let Some(path) = command_line {
+        return checked_resource_location(path)
+            .map_err(|e| format!("Bad --resource-dir: {}", e));
+    }
+    if let Some(path) = std::env::var_os(RESOURCE_DIR_ENV_VAR) {
+        return checked_resource_location(Path::new(&path))
+            .map_err(|e| format!("Bad {}: {}", RESOURCE_DIR_ENV_VAR, e));
+    }
+
+    let mut searched = Vec::new();
+    let exe_dir = std::env::current_exe().ok()
+        .and_then(|exe| exe.canonicalize().ok())
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    let cwd = std::env::current_dir().ok();
+    for start in exe_dir.into_iter().chain(cwd) {
+        for dir in start.ancestors() {
+            let candidate = dir.join("res");
+            if let Ok(path) = checked_resource_dir(&candidate) {
+                return Ok(path);
+            }
+            searched.push(candidate);
+
+            let archive = dir.join(RESOURCE_ARCHIVE_NAME);
+            if archive.is_file() {
+                return checked_resource_location(&archive);
+            }
+        }
+    }
+
+    Err(format!("Could not find the res folder. Pass --resource-dir or set {}. Looked in {:?}", RESOURCE_DIR_ENV_VAR, searched))
+}
+
+fn checked_resource_location(path: &Path) -> StatusOr<PathBuf> {
+    if path.is_file() {
+        return path.canonicalize()
+            .map_err(|e| format!("Couldn't canonicalize {:?}: {}", path, e));
+    }
+    checked_resource_dir(path)
+}
+
+// A resource folder has to at least hold the configs.
+fn checked_resource_dir(path: &Path) -> StatusOr<PathBuf> {
+    let path = canonical_dir(path)?;
+    if !path.join("config").is_dir() {
+        return Err(format!("No config folder in {:?}", path));
+    }
+    Ok(path)
+}
+
+fn mounted_entry(path: &Path) -> Option<(&'static MountedArchive, String)> {
+    let mounted = RESOURCE_ARCHIVE.get()?;
+    let relative = path.strip_prefix(RESOURCE_BASE.get()?).ok()?;
+    Some((mounted, ResourceArchive::key(relative)))
+}
+
+// The mounted archive and the key for path within it, if path is read from the archive rather than from disk.
+fn archive_entry(path: &Path) -> Option<(&'static ResourceArchive, String)> {
+    let (mounted, key) = mounted_entry(path)?;
+    if mounted.loose_files_override && path.exists() {
+        return None;
+    }
+    Some((&mounted.archive, key))
+}
+
+// Archived files can't be edited, so hot reload only works with loose files.
+pub fn is_archived(path: &Path) -> bool {
+    archive_entry(path).is_some()
+}
+
+pub fn is_file(path: &Path) -> bool {
+    match archive_entry(path) {
+        Some((archive, key)) => archive.get(&key).is_some(),
+        None => path.is_file(),
+    }
+}
+
+pub fn is_dir(path: &Path) -> bool {
+    match archive_entry(path) {
+        Some((archive, key)) => archive.is_dir(&key),
+        None => path.is_dir(),
+    }
+}
+
+fn archived_bytes(path: &Path) -> Option<StatusOr<&'static [u8]>> {
+    let (archive, key) = archive_entry(path)?;
+    Some(archive.get(&key).ok_or(format!("No file {:?} in the resource archive", path)))
 }
 
-pub fn reader(path: &PathBuf) -> StatusOr<BufReader<File>> {
+// The per-user config layer, only if one was asked for. Nothing picks one up from the home directory on its own.
+pub fn config_override_dir() -> Option<PathBuf> {
+    CONFIG_OVERRIDE_DIR.get().cloned()
+}
+
+// $XDG_CONFIG_HOME/fortress or ~/.config/fortress, whether or not it exists.
+pub fn user_config_dir() -> StatusOr<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok_or(String::from("Neither XDG_CONFIG_HOME nor HOME is set"))?;
+    Ok(config_home.join("fortress"))
+}
+
+// Fields in configs found in this dir override those in res/config and the active profile.
+pub fn set_config_override_dir(path: &Path) -> StatusOr<()> {
+    let path = canonical_dir(path)?;
+    println!("Config overrides from {:?}", path);
+    CONFIG_OVERRIDE_DIR.set(path)
+        .map_err(|path| format!("Config override dir already set, ignoring {:?}", path))
+}
+
+fn canonical_dir(path: &Path) -> StatusOr<PathBuf> {
+    let path = path.canonicalize()
+        .map_err(|e| format!("Couldn't canonicalize {:?}: {}", path, e))?;
+    if !path.is_dir() {
+        return Err(format!("Not a directory: {:?}", path));
+    }
+    Ok(path)
+}
+
+pub fn reader(path: &PathBuf) -> StatusOr<Box<dyn Read>> {
+    if let Some(bytes) = archived_bytes(path) {
+        return Ok(Box::new(bytes?));
+    }
     let file = File::open(path)
         .map_err(|e| format!("Error opening file {:?}: {}", path, e))?;
-    Ok(BufReader::new(file))
+    Ok(Box::new(BufReader::new(file)))
 }
 
 // Returns base names of files in dir that match .*extension
 pub fn files_in_dir_ending_with(dir: &PathBuf, extension: &str) -> StatusOr<Vec<String>> {
-    Ok(dir.read_dir()
-        .map_err(|e| format!("Bad dir: {:?}", e))?
-        .filter_map(|entry| entry.ok())
-        .map(|entry| entry.file_name())
-        .filter_map(|base_name| {
-            let base_name_str = base_name.to_str()?;
-            if !base_name_str.ends_with(extension) {
-                return None;
-            }
-            Some(String::from(base_name_str))
-        })
+    Ok(dir_entries(dir)?
+        .into_iter()
+        .filter(|base_name| base_name.ends_with(extension) && is_file(&dir.join(base_name)))
         .collect())
 }
 
+// Returns base names of the directories in dir.
+pub fn dirs_in_dir(dir: &PathBuf) -> StatusOr<Vec<String>> {
+    Ok(dir_entries(dir)?
+        .into_iter()
+        .filter(|base_name| is_dir(&dir.join(base_name)))
+        .collect())
+}
+
+// Loose and archived entries together when loose files override the archive.
+fn dir_entries(dir: &PathBuf) -> StatusOr<Vec<String>> {
+    let mut entries = Vec::new();
+    let mut found = false;
+    if let Some((mounted, key)) = mounted_entry(dir) {
+        if mounted.archive.is_dir(&key) {
+            entries.extend(mounted.archive.list_dir(&key));
+            found = true;
+        }
+        if !mounted.loose_files_override {
+            if !found {
+                return Err(format!("Bad dir: no {:?} in the resource archive", dir));
+            }
+            return Ok(entries);
+        }
+    }
+
+    match dir.read_dir() {
+        Ok(read_dir) => entries.extend(read_dir
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().into_string().ok())),
+        Err(e) if !found => return Err(format!("Bad dir: {:?}", e)),
+        Err(_) => {},
+    }
+    entries.sort();
+    entries.dedup();
+    Ok(entries)
+}
+
 pub fn slurp_file(path: &PathBuf) -> StatusOr<String> {
-    let file = File::open(path)
-        .map_err(|e| format!("Error opening file {:?}: {}", path, e))?;
-    let mut buf_reader = BufReader::new(file);
     let mut contents = String::new();
-    buf_reader.read_to_string(&mut contents)
+    reader(path)?.read_to_string(&mut contents)
         .map_err(|e| format!("Error reading to string from slurp_file {:?}: {}", path, e))?;
     Ok(contents)
 }
 
 pub fn slurp_file_bytes(path: &PathBuf) -> StatusOr<Vec<u8>> {
-    let file = File::open(path)
-        .map_err(|e| format!("Error opening file {:?}: {}", path, e))?;
-    let mut buf_reader = BufReader::new(file);
     let mut contents = Vec::new();
-    buf_reader.read_to_end(&mut contents)
+    reader(path)?.read_to_end(&mut contents)
         .map_err(|e| format!("Error reading to string from slurp_file {:?}: {}", path, e))?;
     Ok(contents)
 }
 
-pub fn mmap(path: &PathBuf) -> StatusOr<MmapFile> {
-    MmapFile::read(path)
+pub fn mmap(path: &PathBuf) -> StatusOr<ResourceBytes> {
+    match archived_bytes(path) {
+        Some(bytes) => Ok(ResourceBytes::Archived(bytes?)),
+        None => Ok(ResourceBytes::Mapped(MmapFile::read(path)?)),
+    }
+}
+
+// Packs the loose resource folder into an archive the game can be pointed at instead.
+pub fn pack_resources(out_path: &Path) -> StatusOr<usize> {
+    let base = resource_base();
+    if !base.is_dir() {
+        return Err(format!("Resources are already packed in {:?}", base));
+    }
+    ResourceArchive::pack(&base, out_path)
 }
 
 pub fn resource_path(parent_folder: &'static str, resource_name: &'static str) -> PathBuf {
@@ -78,29 +302,62 @@ pub fn resource_path(parent_folder: &'static str, resource_name: &'static str) -
     path_buf
 }
 
-fn dir_contains_res(path: &Path) -> StatusOr<bool> {
-    for entry in fs::read_dir(path)
-        .map_err(|e| format!("Couldn't read dir {:?}: {}", path, e))? {
-        let entry = entry
-            .map_err(|e| format!("Couldn't read entry in {:?}: {}", path, e))?;
-        if entry.file_name() == OsString::from("res") {
-            return Ok(true)
+pub fn config_path(config_file_name: &str) -> PathBuf {
+    let mut path_buf = resource_base();
+    path_buf.push("config");
+    path_buf.push(config_file_name);
+    path_buf
+}
+
+pub fn config_profiles_dir() -> PathBuf {
+    let mut path_buf = resource_base();
+    path_buf.push("config");
+    path_buf.push("profiles");
+    path_buf
+}
+
+// Names of the directories in res/config/profiles.
+pub fn config_profiles() -> Vec<String> {
+    let mut profiles = dirs_in_dir(&config_profiles_dir()).unwrap_or_default();
+    profiles.sort();
+    profiles
+}
+
+pub fn config_profile() -> Option<String> {
+    CONFIG_PROFILE.read().unwrap().clone()
+}
+
+pub fn set_config_profile(profile: Option<&str>) -> StatusOr<()> {
+    if let Some(profile) = profile {
+        if !is_dir(&config_profiles_dir().join(profile)) {
+            return Err(format!("No config profile {} in {:?}", profile, config_profiles_dir()));
         }
     }
-    Ok(false)
+    *CONFIG_PROFILE.write().unwrap() = profile.map(String::from);
+    Ok(())
 }
 
-fn try_find_resource_base() -> StatusOr<PathBuf> {
-    let root = PathBuf::from(".");
+// The files a config is merged from, in order: res/config, the active profile, then the user's dir.
+pub fn config_layer_paths(config_file_name: &str) -> Vec<PathBuf> {
+    config_layer_paths_for(config_file_name, config_profile().as_deref())
+}
 
-    let mut path_buf = root.canonicalize()
-        .map_err(|e| format! ("Couldn't canonicalize CWD: {}", e))?;
+pub fn config_layer_paths_for(config_file_name: &str, profile: Option<&str>) -> Vec<PathBuf> {
+    let profile_layer = profile.map(|profile| config_profiles_dir().join(profile).join(config_file_name));
+    let user_layer = config_override_dir().map(|dir| dir.join(config_file_name));
+
+    let mut layers = vec!(config_path(config_file_name));
+    layers.extend(profile_layer.into_iter()
+        .chain(user_layer)
+        .filter(|path| is_file(path)));
+    layers
+}
 
-    // TODO: Apply this call recursively instead of stopping.
-    if dir_contains_res(path_buf.as_path())? {
-        path_buf.push("res");
-        Ok(path_buf)
-    } else {
-       Err(String::from("Could not find resource folder!"))
+// The layers of the config outside res/config/profiles, whether or not they exist yet.
+pub fn config_layer_candidates(config_file_name: &str) -> Vec<PathBuf> {
+    let mut candidates = vec!(config_path(config_file_name));
+    if let Some(dir) = config_override_dir() {
+        candidates.push(dir.join(config_file_name));
     }
+    candidates
 }
@@ -0,0 +1,62 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+#[derive(Deserialize)]
+pub struct HudConfig {
+    pub frames: FrameCounterConfig,
+    pub skulls: SkullCounterConfig,
+    pub game_state: GameStateOverlayConfig,
+    pub reload_errors: ReloadErrorOverlayConfig,
+    pub config_profile: ConfigProfileLabelConfig,
+}
+
+#[derive(Deserialize)]
+pub struct FrameCounterConfig {
+    pub num_last_frames_to_average: usize,
+    pub fps_text_screen_pos: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+}
+
+#[derive(Deserialize)]
+pub struct SkullCounterConfig {
+    pub screen_pos: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+    pub drop_pos_offset: (f32, f32, f32),
+    pub drop_color: (f32, f32, f32),
+}
+
+#[derive(Deserialize)]
+pub struct GameStateOverlayConfig {
+    pub heading_pos: (f32, f32, f32),
+    pub prompt_pos: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+}
+
+#[derive(Deserialize)]
+pub struct ReloadErrorOverlayConfig {
+    pub screen_pos: (f32, f32, f32),
+    pub line_height: f32,
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+    pub max_line_chars: usize,
+    pub max_errors: usize,
+}
+
+#[derive(Deserialize)]
+pub struct ConfigProfileLabelConfig {
+    pub screen_pos: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+}
+
+impl Validate for HudConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("frames.num_last_frames_to_average", self.frames.num_last_frames_to_average);
+        validator.positive("reload_errors.max_line_chars", self.reload_errors.max_line_chars);
+    }
+}
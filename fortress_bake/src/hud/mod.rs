@@ -0,0 +1,8 @@
+pub mod hud_config;
+
+pub use self::hud_config::ConfigProfileLabelConfig;
+pub use self::hud_config::FrameCounterConfig;
+pub use self::hud_config::GameStateOverlayConfig;
+pub use self::hud_config::HudConfig;
+pub use self::hud_config::ReloadErrorOverlayConfig;
+pub use self::hud_config::SkullCounterConfig;
@@ -1,7 +1,13 @@
-use crate::items::{
-    barrels::BarrelConfig,
-    ItemType,
-    types::SkullType,
+use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    items::{
+        BarrelConfig,
+        ItemType,
+        types::SkullType,
+    },
 };
 use std::collections::HashMap;
 
@@ -19,4 +25,11 @@ pub struct ItemConfig {
     pub skull_value: HashMap<SkullType, i64>,
 
     pub barrel: BarrelConfig,
-}
\ No newline at end of file
+}
+
+impl Validate for ItemConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("physical_radius", self.physical_radius);
+        validator.positive("physical_density", self.physical_density);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod barrel_config;
+pub mod item_config;
+pub mod types;
+
+pub use self::barrel_config::BarrelConfig;
+pub use self::item_config::ItemConfig;
+pub use self::types::ItemType;
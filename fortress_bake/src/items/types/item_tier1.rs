@@ -3,9 +3,8 @@ use crate::{
         LrDirection,
         Reverse,
     },
-    math::RandGen,
     render::{
-        NamedSpriteSheet,
+        NamedSprite,
         SpriteSheetFrameId,
     },
 };
@@ -26,17 +25,10 @@ impl ItemTier1 {
     }
 
     pub fn sprite_frame_id(self) -> SpriteSheetFrameId {
-        let image = match self {
-            Self::CritChanceBoost => "mushroom.png",
-            Self::NormalFiringSpeedBoost => "normal_firing_speed_glove.png",
+        let sprite = match self {
+            Self::CritChanceBoost => NamedSprite::Mushroom,
+            Self::NormalFiringSpeedBoost => NamedSprite::NormalFiringSpeedGlove,
         };
-        SpriteSheetFrameId::new(String::from(image), NamedSpriteSheet::SpriteSheet1)
-    }
-
-    pub fn random(rng: &mut RandGen) -> Self {
-        match rng.ranged_i64(0, 2) {
-            0 => Self::CritChanceBoost,
-            _ => Self::NormalFiringSpeedBoost,
-        }
+        sprite.frame_id()
     }
 }
\ No newline at end of file
@@ -3,9 +3,8 @@ use crate::{
         LrDirection,
         Reverse,
     },
-    math::RandGen,
     render::{
-        NamedSpriteSheet,
+        NamedSprite,
         SpriteSheetFrameId,
     },
 };
@@ -26,17 +25,10 @@ impl ItemTier2 {
     }
 
     pub fn sprite_frame_id(self) -> SpriteSheetFrameId {
-        let image = match self {
-            Self::CritMultiplierBoost => "crit_multiplier_potion.png",
-            Self::SpecialFiringPeriodBoost => "special_firing_speed_glove.png",
+        let sprite = match self {
+            Self::CritMultiplierBoost => NamedSprite::CritMultiplierPotion,
+            Self::SpecialFiringPeriodBoost => NamedSprite::SpecialFiringSpeedGlove,
         };
-        SpriteSheetFrameId::new(String::from(image), NamedSpriteSheet::SpriteSheet1)
-    }
-
-    pub fn random(rng: &mut RandGen) -> Self {
-        match rng.ranged_i64(0, 2) {
-            0 => Self::CritMultiplierBoost,
-            _ => Self::SpecialFiringPeriodBoost,
-        }
+        sprite.frame_id()
     }
 }
@@ -8,7 +8,6 @@ use crate::{
         ItemTier2,
         SkullType,
     },
-    math::RandGen,
     render::SpriteSheetFrameId,
 };
 
@@ -35,14 +34,4 @@ impl ItemType {
             Self::Tier2(tier2) => tier2.sprite_frame_id(),
         }
     }
-
-    pub fn random(rng: &mut RandGen) -> Self {
-        if rng.flip_coin(0.75) {
-            Self::Skull(SkullType::random(rng))
-        } else if rng.flip_coin(0.75) {
-            Self::Tier1(ItemTier1::random(rng))
-        } else {
-            Self::Tier2(ItemTier2::random(rng))
-        }
-    }
 }
\ No newline at end of file
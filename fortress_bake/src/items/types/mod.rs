@@ -0,0 +1,9 @@
+pub mod item_tier1;
+pub mod item_tier2;
+pub mod item_type;
+pub mod skull_type;
+
+pub use self::item_tier1::ItemTier1;
+pub use self::item_tier2::ItemTier2;
+pub use self::item_type::ItemType;
+pub use self::skull_type::SkullType;
\ No newline at end of file
@@ -3,9 +3,8 @@ use crate::{
         LrDirection,
         Reverse,
     },
-    math::RandGen,
     render::{
-        NamedSpriteSheet,
+        NamedSprite,
         SpriteSheetFrameId,
     },
 };
@@ -27,15 +26,8 @@ impl SkullType {
 
     pub fn sprite_frame_id(self) -> SpriteSheetFrameId {
         match self {
-            Self::Mega => SpriteSheetFrameId::new(String::from("item_mega_skull.png"), NamedSpriteSheet::SpriteSheet1),
-            Self::Regular => SpriteSheetFrameId::new(String::from("item_skull.png"), NamedSpriteSheet::SpriteSheet1),
-        }
-    }
-
-    pub fn random(rng: &mut RandGen) -> Self {
-        match rng.ranged_i64(0, 2) {
-            0 => Self::Mega,
-            _ => Self::Regular,
+            Self::Mega => NamedSprite::MegaSkull.frame_id(),
+            Self::Regular => NamedSprite::Skull.frame_id(),
         }
     }
 }
@@ -1,19 +1,34 @@
 extern crate enum_iterator;
+// So #[derive(Validate)] can name fortress_bake from inside this crate too.
+extern crate self as fortress_bake;
+#[macro_use] extern crate fortress_derive;
 extern crate gl;
 extern crate glm;
-#[macro_use] extern crate lazy_static;
 extern crate memmap;
 extern crate notify;
 extern crate png;
 extern crate rect_packer;
 extern crate ron;
+#[cfg(feature = "rasterize")]
 extern crate rusttype;
 extern crate serde;
+extern crate serde_json;
 #[macro_use] extern crate serde_derive;
 
 pub mod app;
+pub mod audio;
 pub mod bake;
+pub mod control;
 pub mod dimensions;
+pub mod enemies;
 pub mod file;
+pub mod hud;
+pub mod items;
+pub mod maps;
+pub mod particles;
+pub mod physics;
+pub mod players;
+pub mod profiling;
 pub mod render;
 pub mod text;
+pub mod world;
@@ -1,3 +1,8 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
 #[derive(Clone, Deserialize)]
 pub struct MapConfig {
     pub cell_length: f64,
@@ -24,3 +29,10 @@ pub struct MapFileConfig {
     pub generators_count_guess: usize,
     pub barrel_count_guess: usize,
 }
+
+impl Validate for MapConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("cell_length", self.cell_length);
+        validator.positive("map_file.num_fragments", self.map_file.num_fragments);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    app::StatusOr,
+    file::{
+        self,
+        Config,
+        ConfigError,
+        ConfigLint,
+    },
+    render::Png,
+};
+use std::path::PathBuf;
+
+// In theory, we construct a MapFile from multiple images.
+pub struct MapFileFragment {
+    pub image: Png,
+}
+
+impl MapFileFragment {
+    // TODO: in the future, there will be more than one fragment.
+    pub fn check_num_fragments(num_fragments: usize) -> StatusOr<()> {
+        if num_fragments > 1 {
+            return Err(format!("More than one map fragment ({})", num_fragments));
+        }
+        Ok(())
+    }
+
+    // Loads every PNG in res/map like the game does, but reports problems instead of stopping.
+    pub fn lint(lint: &mut ConfigLint) {
+        let mut map_dir = file::util::resource_base();
+        map_dir.push("map");
+
+        let base_names = match file::util::files_in_dir_ending_with(&map_dir, ".png") {
+            Ok(base_names) => base_names,
+            Err(e) => {
+                lint.report(map_dir, ConfigError::from(e));
+                return;
+            },
+        };
+
+        let num_fragments = base_names
+            .into_iter()
+            .filter_map(|base_name| lint.check_file::<MapFileFragment>(&map_dir.join(base_name)))
+            .count();
+        if let Err(e) = Self::check_num_fragments(num_fragments) {
+            lint.report(map_dir, ConfigError::from(e));
+        }
+    }
+}
+
+impl Config for MapFileFragment {
+    fn from_path(path_buf: &PathBuf) -> Result<Self, ConfigError> {
+        Ok(MapFileFragment {
+            image: Png::from_file(path_buf)?
+        })
+    }
+}
@@ -0,0 +1,6 @@
+pub mod map_config;
+pub mod map_file_fragment;
+
+pub use self::map_config::MapConfig;
+pub use self::map_config::MapFileConfig;
+pub use self::map_file_fragment::MapFileFragment;
@@ -0,0 +1,6 @@
+pub mod particle_config;
+
+pub use self::particle_config::BloodParticleConfig;
+pub use self::particle_config::HeroSwitchParticleConfig;
+pub use self::particle_config::ParticleConfig;
+pub use self::particle_config::SnowParticleConfig;
@@ -1,3 +1,8 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
 #[derive(Deserialize)]
 pub struct BloodParticleConfig {
     pub particle_limit: usize,
@@ -48,4 +53,36 @@ pub struct ParticleConfig {
     pub blood: BloodParticleConfig,
     pub snow: SnowParticleConfig,
     pub hero_switch: HeroSwitchParticleConfig,
-}
\ No newline at end of file
+}
+
+impl Validate for ParticleConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.nested("blood", &self.blood);
+        validator.nested("snow", &self.snow);
+        validator.nested("hero_switch", &self.hero_switch);
+    }
+}
+
+impl Validate for BloodParticleConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.at_least("size_range.1", self.size_range.1, self.size_range.0);
+        validator.positive("expiry_duration_micros", self.expiry_duration_micros);
+    }
+}
+
+impl Validate for SnowParticleConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("particle_generation_period_micros", self.particle_generation_period_micros);
+        validator.at_least("speed_range.1", self.speed_range.1, self.speed_range.0);
+        validator.at_least("size_range.1", self.size_range.1, self.size_range.0);
+    }
+}
+
+impl Validate for HeroSwitchParticleConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("max_age_seconds", self.max_age_seconds);
+        validator.at_least("starting_height_band.1", self.starting_height_band.1, self.starting_height_band.0);
+        validator.at_least("xz_speed_band.1", self.xz_speed_band.1, self.xz_speed_band.0);
+        validator.at_least("wave_speed_band.1", self.wave_speed_band.1, self.wave_speed_band.0);
+    }
+}
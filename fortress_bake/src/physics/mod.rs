@@ -0,0 +1,3 @@
+pub mod simulation_config;
+
+pub use self::simulation_config::SimulationConfig;
@@ -0,0 +1,4 @@
+#[derive(Deserialize, Validate)]
+pub struct SimulationConfig {
+    pub force_generator_initial_capacity: usize,
+}
@@ -0,0 +1,9 @@
+use enum_iterator::Sequence;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Sequence)]
+pub enum Hero {
+    CapedWarrior,
+    FireMage,
+    Barbarian,
+    Rogue,
+}
\ No newline at end of file
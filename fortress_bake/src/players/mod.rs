@@ -0,0 +1,9 @@
+pub mod hero;
+pub mod player_config;
+
+pub use self::hero::Hero;
+pub use self::player_config::PlayerConfig;
+pub use self::player_config::PlayerHeroConfig;
+pub use self::player_config::PlayerBulletConfig;
+pub use self::player_config::PlayerItemConfig;
+pub use self::player_config::PlayerSystemConfig;
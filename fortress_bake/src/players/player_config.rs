@@ -0,0 +1,150 @@
+use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    players::Hero,
+};
+use std::collections::HashMap;
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerConfig {
+    pub physical_radius: f64,
+    pub physical_density: f64,
+    pub weapon_physical_offset: f64,
+    pub fire_special_move_freeze_duration_micros: i64,
+    pub switch_hero_duration_micros: i64,
+    pub switch_hero_screen_shake_intensity: f32,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerHeroConfig {
+    pub base_move_speed: f64,
+    pub fire_special_knockback_strength: f64,
+    pub render_offset: (f32, f32),
+    pub render_scale: (f32, f32),
+    // Clips in the Heroes sheet.
+    pub idle_clip: String,
+    pub walking_clip: String,
+    pub render_extra: Option<PlayerHeroExtraRenderConfig>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerHeroExtraRenderConfig {
+    pub bloom_intensity: f32,
+    pub idle_image_extra_name: String,
+    pub walking_image_extra_name: String,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct FiringPeriodConfig {
+    pub baseline_micros: i64,
+    pub shortest_period_micros: i64,
+    pub per_level_decrease_micros: i64,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerBulletConfig {
+    pub normal_firing_period: FiringPeriodConfig,
+    pub special_firing_period: FiringPeriodConfig,
+    pub special_spread_radians: f64,
+    pub special_num_shots: usize,
+    pub special_screen_shake_intensity: f32,
+    pub lifetime_duration_micros: i64,
+    pub physical_radius: f64,
+    pub speed: f64,
+
+    pub base_damage_per_level: i64,
+    pub random_damage_multiplier: i64,
+    pub base_crit_chance: f64,
+    pub max_crit_chance: f64,
+    pub crit_chance_per_level: f64,
+    pub base_on_crit_damage_multiplier: i64,
+    pub max_on_crit_damage_multiplier: i64,
+    pub crit_multiplier_per_level: i64,
+
+    pub knockback_strength: f64,
+    pub render_width: f32,
+    pub render_height: f32,
+    pub render_elevation: f32,
+    // Clips in SpriteSheet1.
+    pub clip_fire: String,
+    pub clip_poison: String,
+    pub clip_ice: String,
+    pub light_attenuation: (f32, f32, f32),
+    pub bloom_intensity: f32,
+    pub light_color_fire: (f32, f32, f32),
+    pub light_color_poison: (f32, f32, f32),
+    pub light_color_ice: (f32, f32, f32),
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerItemConfig {
+    pub collect_animation_num_concurrent_guess: usize,
+    pub collect_animation_duration_micros: i64,
+    pub collect_render_radius: f32,
+    pub collect_animation_spin_radius: f32,
+    pub collect_animation_spin_max_speed: f32,
+    pub collect_animation_max_height: f32,
+    pub collect_attenuation: (f32, f32, f32),
+    pub collect_animation_bloom_intensity: f32,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct PlayerSystemConfig {
+    pub player: PlayerConfig,
+    pub hero: HashMap<Hero, PlayerHeroConfig>,
+    pub bullet: PlayerBulletConfig,
+    pub item: PlayerItemConfig,
+}
+
+
+impl Validate for PlayerSystemConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.nested("player", &self.player);
+        for hero in enum_iterator::all::<Hero>() {
+            match self.hero.get(&hero) {
+                None => validator.require("hero", false, format!("is missing {:?}", hero)),
+                Some(hero_config) => validator.nested(format!("hero[{:?}]", hero), hero_config),
+            }
+        }
+        validator.nested("bullet", &self.bullet);
+    }
+}
+
+impl Validate for PlayerConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("physical_radius", self.physical_radius);
+        validator.positive("physical_density", self.physical_density);
+        validator.at_least("fire_special_move_freeze_duration_micros", self.fire_special_move_freeze_duration_micros, 0);
+        validator.at_least("switch_hero_duration_micros", self.switch_hero_duration_micros, 0);
+    }
+}
+
+impl Validate for PlayerHeroConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.at_least("base_move_speed", self.base_move_speed, 0.0);
+    }
+}
+
+impl Validate for FiringPeriodConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("shortest_period_micros", self.shortest_period_micros);
+        validator.at_least("baseline_micros", self.baseline_micros, self.shortest_period_micros);
+        validator.at_least("per_level_decrease_micros", self.per_level_decrease_micros, 0);
+    }
+}
+
+impl Validate for PlayerBulletConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.nested("normal_firing_period", &self.normal_firing_period);
+        validator.nested("special_firing_period", &self.special_firing_period);
+        validator.positive("special_num_shots", self.special_num_shots);
+        validator.positive("lifetime_duration_micros", self.lifetime_duration_micros);
+        validator.positive("physical_radius", self.physical_radius);
+        validator.in_range("base_crit_chance", self.base_crit_chance, 0.0, 1.0);
+        validator.in_range("max_crit_chance", self.max_crit_chance, 0.0, 1.0);
+        validator.at_least("crit_chance_per_level", self.crit_chance_per_level, 0.0);
+        validator.at_least("max_on_crit_damage_multiplier", self.max_on_crit_damage_multiplier, self.base_on_crit_damage_multiplier);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod profile_section;
+pub mod profiler_config;
+
+pub use self::profile_section::ProfileSection;
+pub use self::profiler_config::FrameGraphConfig;
+pub use self::profiler_config::ProfilerConfig;
+pub use self::profiler_config::ProfilerLegendConfig;
@@ -0,0 +1,70 @@
+use crate::text::NamedText;
+use enum_iterator::Sequence;
+
+// Timed regions of a frame. Nested sections are also counted in their parent's time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Sequence)]
+pub enum ProfileSection {
+    Events,
+    Simulation,
+    WorldPreUpdate,
+    PhysicsStep,
+    ProcessContacts,
+    WorldPostUpdate,
+    ParticleUpdate,
+    RenderUpdate,
+    DrawWorld,
+    DrawSprites,
+    DrawHex,
+    DrawParticles,
+    DrawText,
+    Bloom,
+    Present,
+}
+
+impl ProfileSection {
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn parent(self) -> Option<ProfileSection> {
+        match self {
+            ProfileSection::WorldPreUpdate
+            | ProfileSection::PhysicsStep
+            | ProfileSection::ProcessContacts
+            | ProfileSection::WorldPostUpdate => Some(ProfileSection::Simulation),
+            ProfileSection::ParticleUpdate => Some(ProfileSection::WorldPostUpdate),
+            ProfileSection::DrawSprites
+            | ProfileSection::DrawHex
+            | ProfileSection::DrawParticles
+            | ProfileSection::DrawText => Some(ProfileSection::DrawWorld),
+            _ => None,
+        }
+    }
+
+    pub fn depth(self) -> usize {
+        match self.parent() {
+            Some(parent) => parent.depth() + 1,
+            None => 0,
+        }
+    }
+
+    pub fn label(self) -> NamedText {
+        match self {
+            ProfileSection::Events => NamedText::ProfileEvents,
+            ProfileSection::Simulation => NamedText::ProfileSimulation,
+            ProfileSection::WorldPreUpdate => NamedText::ProfileWorldPreUpdate,
+            ProfileSection::PhysicsStep => NamedText::ProfilePhysicsStep,
+            ProfileSection::ProcessContacts => NamedText::ProfileProcessContacts,
+            ProfileSection::WorldPostUpdate => NamedText::ProfileWorldPostUpdate,
+            ProfileSection::ParticleUpdate => NamedText::ProfileParticleUpdate,
+            ProfileSection::RenderUpdate => NamedText::ProfileRenderUpdate,
+            ProfileSection::DrawWorld => NamedText::ProfileDrawWorld,
+            ProfileSection::DrawSprites => NamedText::ProfileDrawSprites,
+            ProfileSection::DrawHex => NamedText::ProfileDrawHex,
+            ProfileSection::DrawParticles => NamedText::ProfileDrawParticles,
+            ProfileSection::DrawText => NamedText::ProfileDrawText,
+            ProfileSection::Bloom => NamedText::ProfileBloom,
+            ProfileSection::Present => NamedText::ProfilePresent,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    profiling::ProfileSection,
+};
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+pub struct ProfilerConfig {
+    pub history_len: usize,
+    pub show_graph_on_start: bool,
+    pub graph: FrameGraphConfig,
+    pub legend: ProfilerLegendConfig,
+    // Only top level sections are stacked in the graph.
+    pub section_colors: HashMap<ProfileSection, (f32, f32, f32)>,
+}
+
+#[derive(Deserialize)]
+pub struct FrameGraphConfig {
+    // Bottom-left corner and size as fractions of the screen.
+    pub screen_pos: (f32, f32),
+    pub size: (f32, f32),
+    pub full_scale_micros: i64,
+    pub budget_micros: i64,
+    pub background_color: (f32, f32, f32, f32),
+    pub budget_line_color: (f32, f32, f32, f32),
+}
+
+#[derive(Deserialize)]
+pub struct ProfilerLegendConfig {
+    // Position of the first line. Later lines go down the screen.
+    pub screen_pos: (f32, f32, f32),
+    pub line_height: f32,
+    pub indent: f32,
+    pub color: (f32, f32, f32),
+    pub alpha: f32,
+}
+
+impl Validate for ProfilerConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("history_len", self.history_len);
+        validator.positive("graph.full_scale_micros", self.graph.full_scale_micros);
+        validator.positive("legend.line_height", self.legend.line_height);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+#[derive(Deserialize)]
+pub struct BackgroundRendererConfig {
+    pub camera_speed: f32,
+    // Screen pixels / pixels sampled.
+    pub zoom: f32
+}
+
+impl Validate for BackgroundRendererConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("zoom", self.zoom);
+    }
+}
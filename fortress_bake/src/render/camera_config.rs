@@ -0,0 +1,53 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+#[derive(Deserialize)]
+pub struct CameraConfig {
+    pub zoom: f32,
+    pub z_near: f32,
+    pub z_far: f32,
+    pub lookat: (f32, f32, f32),
+    pub right: (f32, f32, f32),
+    pub camera_pos_offset: (f32, f32),
+    pub initial_position_when_no_players: (f64, f64, f64),
+
+    pub physical_no_move_half_lengths: (f64, f64),
+    pub physical_follow_player_factor: f64,
+
+    pub stream_inside_half_extents: (f64, f64),
+    pub stream_margin_length: f64,
+    pub stream_light_margin_length: f64,
+
+    pub screen_shake: ScreenShakeConfig,
+}
+
+#[derive(Deserialize)]
+pub struct ScreenShakeConfig {
+    pub intensity_fall_off_speed: f32,
+    pub max_intensity: f32,
+    pub max_rotation_radians: f32,
+    pub noise_time_multiplier: f32,
+    pub noise_seed_offset: f32,
+    pub noise_iterations: usize,
+}
+
+impl Validate for CameraConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("zoom", self.zoom);
+        validator.positive("z_near", self.z_near);
+        validator.require("z_far", self.z_far > self.z_near, format!("must be greater than z_near ({}) but is {}", self.z_near, self.z_far));
+        validator.at_least("physical_follow_player_factor", self.physical_follow_player_factor, 0.0);
+        validator.nested("screen_shake", &self.screen_shake);
+    }
+}
+
+impl Validate for ScreenShakeConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.at_least("intensity_fall_off_speed", self.intensity_fall_off_speed, 0.0);
+        validator.at_least("max_intensity", self.max_intensity, 0.0);
+        validator.at_least("max_rotation_radians", self.max_rotation_radians, 0.0);
+        validator.positive("noise_iterations", self.noise_iterations);
+    }
+}
@@ -1,5 +1,9 @@
 pub mod attribute;
+pub mod background_renderer_config;
+pub mod bloom_config;
+pub mod camera_config;
 pub mod png;
+pub mod point_lights_config;
 pub mod serializable_bitmap;
 pub mod sprites;
 pub mod textures;
@@ -8,14 +12,26 @@ pub use self::attribute::Attribute;
 pub use self::attribute::AttributeAdvance;
 pub use self::attribute::AttributeProgram;
 pub use self::attribute::AttributeProgramBuilder;
+pub use self::background_renderer_config::BackgroundRendererConfig;
+pub use self::bloom_config::BloomConfig;
+pub use self::camera_config::CameraConfig;
+pub use self::camera_config::ScreenShakeConfig;
 pub use self::png::Png;
+pub use self::point_lights_config::PointLightsConfig;
 pub use self::serializable_bitmap::SerializableBitmap;
 pub use self::sprites::AllPackedSpriteSheets;
+pub use self::sprites::AnimationClip;
+pub use self::sprites::AsepriteSheet;
 pub use self::sprites::BakedSpriteSheetConfig;
+pub use self::sprites::ClipFrames;
 pub use self::sprites::FramesInfo;
+pub use self::sprites::LoopMode;
+pub use self::sprites::NamedSprite;
 pub use self::sprites::NamedSpriteSheet;
 pub use self::sprites::PackedSpriteSheet;
+pub use self::sprites::QuadBounds;
 pub use self::sprites::SheetConfig;
+pub use self::sprites::SpriteFrames;
 pub use self::sprites::SpriteConfig;
 pub use self::sprites::SpriteSheetConfig;
 pub use self::sprites::SpriteSheetFrameId;
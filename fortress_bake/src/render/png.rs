@@ -88,6 +88,61 @@ impl Png {
         (self.width, self.height)
     }
 
+    pub fn crop(&self, left: usize, top: usize, width: usize, height: usize) -> StatusOr<Png> {
+        if left + width > self.width || top + height > self.height {
+            return Err(format!("Cannot crop {}x{} at ({}, {}) from a {}x{} PNG.", width, height, left, top, self.width, self.height));
+        }
+
+        let mut out = Self::empty(width, height);
+        for y in 0..height {
+            let self_idx_start = 4 * ((y + top) * self.width + left);
+            let out_idx_start = 4 * y * width;
+            out.img[out_idx_start..out_idx_start + 4 * width].copy_from_slice(&self.img[self_idx_start..self_idx_start + 4 * width]);
+        }
+        Ok(out)
+    }
+
+    // The smallest (left, top, right, bottom) box holding every pixel with nonzero alpha, inclusive.
+    pub fn opaque_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.img[4 * (y * self.width + x) + 3] == 0 {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    None => (x, y, x, y),
+                    Some((left, top, right, bottom)) => (left.min(x), top.min(y), right.max(x), bottom.max(y)),
+                });
+            }
+        }
+        bounds
+    }
+
+    // Copies the edge pixels of the width x height region at (left, top) outward by amount pixels, corners included.
+    pub fn extrude(&mut self, left: usize, top: usize, width: usize, height: usize, amount: usize) {
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let min_x = left.saturating_sub(amount);
+        let max_x = (left + width + amount).min(self.width);
+        let min_y = top.saturating_sub(amount);
+        let max_y = (top + height + amount).min(self.height);
+        for y in min_y..max_y {
+            let source_y = y.max(top).min(top + height - 1);
+            for x in min_x..max_x {
+                let source_x = x.max(left).min(left + width - 1);
+                if source_x == x && source_y == y {
+                    continue;
+                }
+                let source_idx = 4 * (source_y * self.width + source_x);
+                let idx = 4 * (y * self.width + x);
+                self.img.copy_within(source_idx..source_idx + 4, idx);
+            }
+        }
+    }
+
     pub fn overwrite(&mut self, other: Png, top_left_x: usize, top_left_y: usize) -> StatusOr<()> {
         let (self_width, self_height) = self.size();
         let (other_width, other_height) = other.size();
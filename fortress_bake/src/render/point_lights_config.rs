@@ -0,0 +1,19 @@
+use crate::file::{
+    ConfigValidator,
+    Validate,
+};
+
+// Size of the lights uniform array in hex_frag.glsl and light_dependent_sprite_frag.glsl.
+pub const MAX_NUM_LIGHTS: usize = 339;
+
+#[derive(Deserialize)]
+pub struct PointLightsConfig {
+    // Also the most lights drawn in a frame. Lights past it are dropped.
+    pub initial_capacity: usize,
+}
+
+impl Validate for PointLightsConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.in_range("initial_capacity", self.initial_capacity, 1, MAX_NUM_LIGHTS);
+    }
+}
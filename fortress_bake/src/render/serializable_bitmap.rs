@@ -34,6 +34,18 @@ impl SerializableBitmap {
             .map_err(|e| format!("Bitmap read err: {:?}", e))
     }
 
+    // One byte per pixel, rows top to bottom.
+    pub fn from_raw(img: Vec<u8>, width: usize, height: usize) -> StatusOr<Self> {
+        if img.len() != width * height {
+            return Err(format!("Raw bitmap has {} bytes, expected {}x{}", img.len(), width, height));
+        }
+        Ok(SerializableBitmap {
+            img,
+            width,
+            height,
+        })
+    }
+
     pub fn try_set_byte(&mut self, x: usize, y: usize, value: u8) {
         let index = y * self.width + x;
         self.img[index] = value;
@@ -0,0 +1,164 @@
+use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    render::{
+        NamedSpriteSheet,
+        SpriteSheetFrameId,
+    },
+};
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub enum LoopMode {
+    Once,
+    #[default]
+    Loop,
+    PingPong,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub enum ClipFrames {
+    // Every frame of the image in order. The count isn't known until the image is packed, so these clips can only loop.
+    #[default]
+    All,
+    // (first, count)
+    Range(usize, usize),
+    List(Vec<usize>),
+}
+
+impl ClipFrames {
+    pub fn count(&self) -> Option<usize> {
+        match self {
+            Self::All => None,
+            Self::Range(_, count) => Some(*count),
+            Self::List(frames) => Some(frames.len()),
+        }
+    }
+
+    pub fn frame(&self, position: usize) -> usize {
+        match self {
+            Self::All => position,
+            Self::Range(first, _) => first + position,
+            Self::List(frames) => frames[position],
+        }
+    }
+}
+
+// Positions are indices into the clip's frames, not the image's.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AnimationClip {
+    pub image: String,
+    #[serde(default)]
+    pub frames: ClipFrames,
+    pub frame_duration_micros: i64,
+    // Overrides frame_duration_micros by position.
+    #[serde(default)]
+    pub frame_durations_micros: HashMap<usize, i64>,
+    #[serde(default)]
+    pub loop_mode: LoopMode,
+    // Named events fired when a position is entered. Events naming a Sound, such as "EnemyKilled", play it.
+    #[serde(default)]
+    pub events: HashMap<usize, Vec<String>>,
+}
+
+impl AnimationClip {
+    pub fn frame_id(&self, sprite_sheet: NamedSpriteSheet) -> SpriteSheetFrameId {
+        SpriteSheetFrameId::new(self.image.clone(), sprite_sheet)
+    }
+
+    // None for clips that play every frame of their image.
+    pub fn duration_micros(&self) -> Option<i64> {
+        let cycle_len = self.cycle_len()?;
+        Some((0..cycle_len).map(|step| self.step_duration(step)).sum())
+    }
+
+    // How many frames have been stepped through after time_elapsed. Once clips stop on their last.
+    pub fn step_at(&self, time_elapsed: i64) -> usize {
+        let time_elapsed = std::cmp::max(time_elapsed, 0);
+        let (cycle_len, duration) = match (self.cycle_len(), self.duration_micros()) {
+            (Some(cycle_len), Some(duration)) => (cycle_len, duration),
+            _ => return (time_elapsed / self.frame_duration_micros) as usize,
+        };
+
+        let (num_cycles, mut time_left) = match self.loop_mode {
+            LoopMode::Once if time_elapsed >= duration => return cycle_len - 1,
+            LoopMode::Once => (0, time_elapsed),
+            _ => (time_elapsed / duration, time_elapsed % duration),
+        };
+
+        let mut step = 0;
+        while step + 1 < cycle_len && time_left >= self.step_duration(step) {
+            time_left -= self.step_duration(step);
+            step += 1;
+        }
+        num_cycles as usize * cycle_len + step
+    }
+
+    pub fn position_at_step(&self, step: usize) -> usize {
+        match (self.frames.count(), self.cycle_len()) {
+            (Some(len), Some(cycle_len)) => {
+                let step = match self.loop_mode {
+                    LoopMode::Once => std::cmp::min(step, cycle_len - 1),
+                    _ => step % cycle_len,
+                };
+                if step < len {
+                    step
+                } else {
+                    2 * len - 2 - step
+                }
+            },
+            _ => step,
+        }
+    }
+
+    // The image frame to draw after time_elapsed.
+    pub fn frame_at(&self, time_elapsed: i64) -> usize {
+        self.frames.frame(self.position_at_step(self.step_at(time_elapsed)))
+    }
+
+    pub fn events_at(&self, position: usize) -> &[String] {
+        self.events.get(&position).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    // Steps in one pass through the clip. Ping-pong clips don't repeat their first or last frame on the way back.
+    fn cycle_len(&self) -> Option<usize> {
+        let len = self.frames.count()?;
+        match self.loop_mode {
+            LoopMode::PingPong if len > 1 => Some(2 * len - 2),
+            _ => Some(len),
+        }
+    }
+
+    fn step_duration(&self, step: usize) -> i64 {
+        let position = self.position_at_step(step);
+        *self.frame_durations_micros.get(&position).unwrap_or(&self.frame_duration_micros)
+    }
+}
+
+impl Validate for AnimationClip {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("frame_duration_micros", self.frame_duration_micros);
+        for (position, duration) in self.frame_durations_micros.iter() {
+            validator.positive(format!("frame_durations_micros[{}]", position), *duration);
+        }
+
+        match self.frames.count() {
+            None => {
+                validator.require("loop_mode", self.loop_mode == LoopMode::Loop, "must be Loop when playing All frames");
+                validator.require("frame_durations_micros", self.frame_durations_micros.is_empty(), "needs a Range or List of frames");
+                validator.require("events", self.events.is_empty(), "needs a Range or List of frames");
+            },
+            Some(len) => {
+                validator.positive("frames", len);
+                for position in self.frame_durations_micros.keys().filter(|position| **position >= len) {
+                    validator.require(format!("frame_durations_micros[{}]", position), false, "is past the last frame");
+                }
+                for position in self.events.keys().filter(|position| **position >= len) {
+                    validator.require(format!("events[{}]", position), false, "is past the last frame");
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    app::StatusOr,
+    file,
+    render::{
+        AnimationClip,
+        ClipFrames,
+        LoopMode,
+        NamedSpriteSheet,
+        Png,
+        SpriteFrames,
+    },
+};
+use serde::de::{
+    Deserialize,
+    Deserializer,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+// What Aseprite uses for frames exported without a duration.
+const DEFAULT_FRAME_DURATION_MILLIS: i64 = 100;
+
+// A sheet exported from Aseprite as a JSON hash or array next to its PNG. Each tag becomes a sprite named
+// "<json file>#<tag>", and each frame outside every tag a sprite named "<json file>#<frame file name>". TexturePacker's
+// JSON hash and array exports read the same way, without durations or tags.
+pub struct AsepriteSheet {
+    json_name: String,
+    frames: Vec<(String, JsonFrame)>,
+    meta: JsonMeta,
+}
+
+// The frames of one imported sprite, by index into the sheet's frames.
+struct AsepriteSprite {
+    name: String,
+    frames: Vec<usize>,
+    loop_mode: LoopMode,
+}
+
+impl AsepriteSheet {
+    // Every sheet in dir, by name.
+    pub fn read_all(dir: &PathBuf) -> StatusOr<Vec<AsepriteSheet>> {
+        let mut json_names = file::util::files_in_dir_ending_with(dir, ".json")?;
+        json_names.sort();
+        json_names.into_iter()
+            .map(|json_name| Self::from_file(dir, json_name))
+            .collect()
+    }
+
+    // The clips of every sheet in each sprite sheet's image folder.
+    pub fn clips_in(images_dir: &PathBuf) -> StatusOr<HashMap<NamedSpriteSheet, HashMap<String, AnimationClip>>> {
+        let mut clips = HashMap::new();
+        for sprite_sheet in NamedSpriteSheet::all_values() {
+            let dir = images_dir.join(sprite_sheet.to_directory_basename());
+            if !file::util::is_dir(&dir) {
+                continue;
+            }
+            let sheet_clips = Self::read_all(&dir)?
+                .iter()
+                .flat_map(AsepriteSheet::clips)
+                .collect();
+            clips.insert(sprite_sheet, sheet_clips);
+        }
+        Ok(clips)
+    }
+
+    pub fn from_file(dir: &PathBuf, json_name: String) -> StatusOr<AsepriteSheet> {
+        let contents = file::util::slurp_file(&dir.join(&json_name))?;
+        let json: JsonSheet = serde_json::from_str(&contents)
+            .map_err(|e| format!("Couldn't read {}: {}", json_name, e))?;
+        let sheet = AsepriteSheet {
+            json_name,
+            frames: json.frames.0,
+            meta: json.meta,
+        };
+        for tag in sheet.meta.frame_tags.iter() {
+            if tag.from > tag.to || tag.to >= sheet.frames.len() {
+                return Err(format!("Tag {} in {} covers frames {} to {} of {}", tag.name, sheet.json_name, tag.from, tag.to, sheet.frames.len()));
+            }
+        }
+        Ok(sheet)
+    }
+
+    // The PNG the frames are cut from, next to the JSON. Aseprite writes the path it exported to, so only its file name
+    // is kept.
+    pub fn image_name(&self) -> &str {
+        Path::new(&self.meta.image)
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .unwrap_or(&self.meta.image)
+    }
+
+    pub fn read_sprites(&self, dir: &PathBuf, trim: bool) -> StatusOr<Vec<(String, SpriteFrames)>> {
+        let image = Png::from_file(&dir.join(self.image_name()))?;
+        self.sprites()
+            .into_iter()
+            .map(|sprite| {
+                let frames = sprite.frames.iter()
+                    .map(|index| self.frames[*index].1.read(&image))
+                    .collect::<StatusOr<Vec<Png>>>()
+                    .and_then(|frames| SpriteFrames::from_frames(frames, trim))
+                    .map_err(|e| format!("Couldn't import {}: {}", sprite.name, e))?;
+                Ok((sprite.name, frames))
+            })
+            .collect()
+    }
+
+    // A clip for every imported sprite, named after it and timed by the exported durations.
+    pub fn clips(&self) -> Vec<(String, AnimationClip)> {
+        self.sprites()
+            .into_iter()
+            .map(|sprite| {
+                let durations: Vec<i64> = sprite.frames.iter()
+                    .map(|index| self.frames[*index].1.duration.unwrap_or(DEFAULT_FRAME_DURATION_MILLIS) * 1000)
+                    .collect();
+                let frame_duration_micros = durations[0];
+                let frame_durations_micros = durations.iter()
+                    .cloned()
+                    .enumerate()
+                    .filter(|(_, duration)| *duration != frame_duration_micros)
+                    .collect();
+
+                let clip = AnimationClip {
+                    image: sprite.name.clone(),
+                    frames: ClipFrames::Range(0, durations.len()),
+                    frame_duration_micros,
+                    frame_durations_micros,
+                    loop_mode: sprite.loop_mode,
+                    events: HashMap::new(),
+                };
+                (sprite.name, clip)
+            })
+            .collect()
+    }
+
+    fn sprites(&self) -> Vec<AsepriteSprite> {
+        let mut tagged = vec!(false; self.frames.len());
+        let mut sprites = Vec::with_capacity(self.meta.frame_tags.len());
+        for tag in self.meta.frame_tags.iter() {
+            let mut frames: Vec<usize> = (tag.from..=tag.to).collect();
+            if tag.direction.ends_with("reverse") {
+                frames.reverse();
+            }
+            for index in frames.iter() {
+                tagged[*index] = true;
+            }
+
+            let loop_mode = if tag.direction.starts_with("pingpong") {
+                LoopMode::PingPong
+            } else if tag.repeat.as_deref() == Some("1") {
+                LoopMode::Once
+            } else {
+                LoopMode::Loop
+            };
+
+            sprites.push(AsepriteSprite {
+                name: format!("{}#{}", self.json_name, tag.name),
+                frames,
+                loop_mode,
+            });
+        }
+
+        for (index, (frame_name, _)) in self.frames.iter().enumerate().filter(|(index, _)| !tagged[*index]) {
+            sprites.push(AsepriteSprite {
+                name: format!("{}#{}", self.json_name, frame_name),
+                frames: vec!(index),
+                loop_mode: LoopMode::Loop,
+            });
+        }
+        sprites
+    }
+}
+
+#[derive(Deserialize)]
+struct JsonSheet {
+    frames: JsonFrames,
+    meta: JsonMeta,
+}
+
+#[derive(Deserialize)]
+struct JsonMeta {
+    image: String,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<JsonTag>,
+}
+
+#[derive(Deserialize)]
+struct JsonTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: String,
+    // How many times to play. Left out, the tag loops.
+    repeat: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JsonRect {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+}
+
+#[derive(Deserialize)]
+struct JsonSize {
+    w: usize,
+    h: usize,
+}
+
+#[derive(Deserialize)]
+struct JsonFrame {
+    #[serde(default)]
+    filename: String,
+    frame: JsonRect,
+    #[serde(default)]
+    rotated: bool,
+    #[serde(default)]
+    trimmed: bool,
+    #[serde(rename = "spriteSourceSize")]
+    sprite_source_size: Option<JsonRect>,
+    #[serde(rename = "sourceSize")]
+    source_size: Option<JsonSize>,
+    // Milliseconds.
+    duration: Option<i64>,
+}
+
+impl JsonFrame {
+    // Cuts the frame out of the sheet image and puts back any transparent border the export trimmed away.
+    fn read(&self, image: &Png) -> StatusOr<Png> {
+        if self.rotated {
+            return Err(format!("{} is rotated, which isn't supported. Export without rotation.", self.filename));
+        }
+        let frame = image.crop(self.frame.x, self.frame.y, self.frame.w, self.frame.h)?;
+        match (self.trimmed, &self.sprite_source_size, &self.source_size) {
+            (true, Some(offset), Some(source_size)) => {
+                let mut untrimmed = Png::empty(source_size.w, source_size.h);
+                untrimmed.overwrite(frame, offset.x, offset.y)?;
+                Ok(untrimmed)
+            },
+            _ => Ok(frame),
+        }
+    }
+}
+
+// Frames in the order they're written. The hash export keys them by file name, but tags still refer to them by position.
+struct JsonFrames(Vec<(String, JsonFrame)>);
+
+impl<'de> Deserialize<'de> for JsonFrames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonFramesVisitor)
+    }
+}
+
+struct JsonFramesVisitor;
+
+impl<'de> Visitor<'de> for JsonFramesVisitor {
+    type Value = JsonFrames;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of frames or a map of file names to frames")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut frames = Vec::new();
+        while let Some(frame) = seq.next_element::<JsonFrame>()? {
+            frames.push((frame.filename.clone(), frame));
+        }
+        Ok(JsonFrames(frames))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut frames = Vec::new();
+        while let Some((filename, mut frame)) = map.next_entry::<String, JsonFrame>()? {
+            frame.filename = filename.clone();
+            frames.push((filename, frame));
+        }
+        Ok(JsonFrames(frames))
+    }
+}
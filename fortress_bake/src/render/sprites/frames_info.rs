@@ -2,8 +2,8 @@ use crate::{
     app::StatusOr,
     dimensions::Reverse,
     render::{
-        SheetConfig,
-        SpriteConfig,
+        QuadBounds,
+        SpriteFrames,
         Texel,
     },
 };
@@ -23,34 +23,42 @@ pub struct FramesInfo {
     // Frame sub-{width,height} correspond to distances within a single frame.
     sub_frame_width: f32,
     sub_frame_height: f32,
+
+    // Fractions of the untrimmed frame cut off each side: (left, top, right, bottom).
+    #[serde(default)]
+    trim: (f32, f32, f32, f32),
 }
 
 impl FramesInfo {
-    pub fn from_rect_pack(config: &SheetConfig, sprite: &SpriteConfig, rect: Rect) -> StatusOr<Self> {
-        if rect.width == 0 || rect.height == 0 {
-            return Err(format!("Bad FramesInfo rect: {}, {}", rect.width as usize, sprite.frame_width));
+    pub fn from_rect_pack(sheet_size: (usize, usize), frames: &SpriteFrames, padding: usize, rect: Rect) -> StatusOr<Self> {
+        let (trimmed_width, trimmed_height) = frames.trimmed_size();
+        if rect.width == 0 || rect.height == 0 || trimmed_width == 0 || trimmed_height == 0 {
+            return Err(format!("Bad FramesInfo rect: {}, {}", rect.width as usize, trimmed_width));
         }
 
-        let num_frames_horizontal = (rect.width as usize) / sprite.frame_width;
-        let num_frames_vertical = (rect.height as usize) / sprite.frame_height;
+        let (sheet_width, sheet_height) = (sheet_size.0 as f32, sheet_size.1 as f32);
+        let left_center = (rect.x as usize + padding) as f32 + 0.5;
+        let top_center = sheet_height - (rect.y as usize + padding) as f32 - 0.5;
+        let texel_top_left = (left_center / sheet_width, top_center / sheet_height);
 
-        let left_center = rect.x as f32 + 0.5;
-        let top_center = (config.height as i32 - rect.y) as f32 - 0.5;
-        let texel_top_left = (left_center / config.width as f32, top_center / config.height as f32);
+        let frame_width = (trimmed_width + 2 * padding) as f32 / sheet_width;
+        let frame_height = (trimmed_height + 2 * padding) as f32 / sheet_height;
+        let sub_frame_width = (trimmed_width - 1) as f32 / sheet_width;
+        let sub_frame_height = (trimmed_height - 1) as f32 / sheet_height;
 
-        let frame_width = sprite.frame_width as f32 / config.width as f32;
-        let frame_height = sprite.frame_height as f32 / config.height as f32;
-        let sub_frame_width = (sprite.frame_width - 1) as f32 / config.width as f32;
-        let sub_frame_height = (sprite.frame_height - 1) as f32 / config.height as f32;
+        let (left, top, right, bottom) = frames.trim;
+        let (untrimmed_width, untrimmed_height) = (frames.frame_width as f32, frames.frame_height as f32);
+        let trim = (left as f32 / untrimmed_width, top as f32 / untrimmed_height, right as f32 / untrimmed_width, bottom as f32 / untrimmed_height);
 
         Ok(FramesInfo {
-            num_frames_horizontal,
-            num_frames_vertical,
+            num_frames_horizontal: frames.num_frames_horizontal,
+            num_frames_vertical: frames.num_frames_vertical,
             texel_top_left,
             frame_width,
             frame_height,
             sub_frame_width,
             sub_frame_height,
+            trim,
         })
     }
 
@@ -76,5 +84,29 @@ impl FramesInfo {
             top_right: glm::vec2(sub_frame_right, sub_frame_top),
         }
     }
-}
 
+    // Where the texel goes on the sprite's quad, so trimmed frames keep their place.
+    pub fn quad_bounds(&self, reverse: Reverse) -> QuadBounds {
+        let (left, top, right, bottom) = self.trim;
+        let mut quad_left = 2.0 * left - 1.0;
+        let mut quad_right = 1.0 - 2.0 * right;
+        let mut quad_bottom = 2.0 * bottom - 1.0;
+        let mut quad_top = 1.0 - 2.0 * top;
+
+        if reverse.horizontally {
+            let reversed_left = -quad_right;
+            quad_right = -quad_left;
+            quad_left = reversed_left;
+        }
+        if reverse.vertically {
+            let reversed_bottom = -quad_top;
+            quad_top = -quad_bottom;
+            quad_bottom = reversed_bottom;
+        }
+
+        QuadBounds {
+            bottom_left: glm::vec2(quad_left, quad_bottom),
+            top_right: glm::vec2(quad_right, quad_top),
+        }
+    }
+}
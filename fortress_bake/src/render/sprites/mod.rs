@@ -1,16 +1,28 @@
 pub mod all_packed_sprite_sheets;
+pub mod animation_clip;
+pub mod aseprite_sheet;
 pub mod baked_sprite_sheet_config;
 pub mod frames_info;
+pub mod named_sprite;
 pub mod named_sprite_sheet;
 pub mod packed_sprite_sheet;
+pub mod quad_bounds;
+pub mod sprite_frames;
 pub mod sprite_sheet_config;
 pub mod sprite_sheet_frame_id;
 
 pub use self::all_packed_sprite_sheets::AllPackedSpriteSheets;
+pub use self::animation_clip::AnimationClip;
+pub use self::animation_clip::ClipFrames;
+pub use self::animation_clip::LoopMode;
+pub use self::aseprite_sheet::AsepriteSheet;
 pub use self::baked_sprite_sheet_config::BakedSpriteSheetConfig;
 pub use self::frames_info::FramesInfo;
+pub use self::named_sprite::NamedSprite;
 pub use self::named_sprite_sheet::NamedSpriteSheet;
 pub use self::packed_sprite_sheet::PackedSpriteSheet;
+pub use self::quad_bounds::QuadBounds;
+pub use self::sprite_frames::SpriteFrames;
 pub use self::sprite_sheet_config::SheetConfig;
 pub use self::sprite_sheet_config::SpriteConfig;
 pub use self::sprite_sheet_config::SpriteSheetConfig;
@@ -0,0 +1,42 @@
+use crate::render::{
+    NamedSpriteSheet,
+    SpriteSheetFrameId,
+};
+use enum_iterator::Sequence;
+
+// Sprites the game draws by name. Animated sprites are clips in sprite_sheet.conf instead.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Sequence)]
+pub enum NamedSprite {
+    CritMultiplierPotion,
+    EnemyGenerator,
+    Jar,
+    Lantern,
+    MegaSkull,
+    Mushroom,
+    NormalFiringSpeedGlove,
+    RockTexture,
+    Skull,
+    SpecialFiringSpeedGlove,
+}
+
+impl NamedSprite {
+    pub fn frame_id(self) -> SpriteSheetFrameId {
+        let image = match self {
+            Self::CritMultiplierPotion => "crit_multiplier_potion.png",
+            Self::EnemyGenerator => "enemy_generator.png",
+            Self::Jar => "jar.png",
+            Self::Lantern => "lantern.png",
+            Self::MegaSkull => "item_mega_skull.png",
+            Self::Mushroom => "mushroom.png",
+            Self::NormalFiringSpeedGlove => "normal_firing_speed_glove.png",
+            Self::RockTexture => "rock_texture.png",
+            Self::Skull => "item_skull.png",
+            Self::SpecialFiringSpeedGlove => "special_firing_speed_glove.png",
+        };
+        SpriteSheetFrameId::new(String::from(image), NamedSpriteSheet::SpriteSheet1)
+    }
+
+    pub fn all_values() -> impl Iterator<Item=NamedSprite> {
+        enum_iterator::all::<Self>()
+    }
+}
@@ -1,81 +1,141 @@
 use crate::{
     app::StatusOr,
+    file,
     render::{
+        AsepriteSheet,
         FramesInfo,
         NamedSpriteSheet,
         Png,
         SheetConfig,
         SpriteConfig,
+        SpriteFrames,
         SpriteSheetFrameId,
     }
 };
-use rect_packer::DensePacker;
+use rect_packer::{
+    DensePacker,
+    Rect,
+};
 use std::path::PathBuf;
 
+// Sheets sized automatically never grow past this on either side.
+const MAX_SHEET_SIZE: usize = 8192;
+
 pub struct PackedSpriteSheet {
     pub image: Png,
-    pub mappings: Vec<(SpriteSheetFrameId, FramesInfo)>
+    pub mappings: Vec<(SpriteSheetFrameId, FramesInfo)>,
+    // Fraction of the sheet covered by sprites and their padding.
+    pub fill_ratio: f32,
 }
 
 impl PackedSpriteSheet {
     pub fn new(config: &SheetConfig, images_dir: &PathBuf, sprite_sheet: NamedSpriteSheet) -> StatusOr<PackedSpriteSheet> {
         let sprite_sheet_dir = Self::base_directory(images_dir, sprite_sheet);
-        let images = Self::read_images_with_identifiers(sprite_sheet_dir)?;
-
-        let mut out_image = Png::empty(config.width, config.height);
-        let mut mappings = Vec::with_capacity(images.len());
+        let imported = AsepriteSheet::read_all(&sprite_sheet_dir)?;
+        // Images an import describes are only read through it.
+        let mut images = Self::read_images_with_identifiers(&sprite_sheet_dir)?;
+        images.retain(|(name, _)| !imported.iter().any(|sheet| sheet.image_name() == name));
 
-        let mut packer = DensePacker::new(config.width as i32, config.height as i32);
+        let mut sprites = Vec::with_capacity(images.len());
+        for sheet in imported.iter() {
+            sprites.extend(sheet.read_sprites(&sprite_sheet_dir, config.trim)?);
+        }
         for (name, image) in images.into_iter() {
             let (image_width, image_height) = image.size();
-            let rect = packer.pack(image_width as i32, image_height as i32, false)
-                .ok_or(format!("PackerSpriteSheetConfig too small for {:?}", sprite_sheet))?;
-
-            out_image.overwrite(image, rect.x as usize, rect.y as usize)?;
+            let sprite = config.sprites.get(&name).cloned().unwrap_or(SpriteConfig {
+                frame_width: image_width,
+                frame_height: image_height,
+            });
+            let frames = SpriteFrames::new(&image, &sprite, config.trim)
+                .map_err(|e| format!("Couldn't split {} in {:?}: {}", name, sprite_sheet, e))?;
+            sprites.push((name, frames));
+        }
+        // Largest first, by the room they take once trimmed and padded.
+        sprites.sort_by_key(|(_, frames)| {
+            let (width, height) = frames.packed_size(config.padding);
+            std::cmp::Reverse(width * height)
+        });
 
-            let frame_id = SpriteSheetFrameId::new(name, sprite_sheet);
-            let frame_info = match config.sprites.get(frame_id.name()) {
-                None => {
-                    let sprite = SpriteConfig {
-                        frame_width: rect.width as usize,
-                        frame_height: rect.height as usize,
-                    };
-                    FramesInfo::from_rect_pack(config, &sprite, rect)?
-                },
-                Some(sprite) => FramesInfo::from_rect_pack(config, sprite, rect)?,
-            };
-            mappings.push((frame_id, frame_info));
+        let (sheet_size, rects) = Self::pack(config, &sprites, sprite_sheet)?;
+        let sprite_area: usize = rects.iter().map(|rect| (rect.width * rect.height) as usize).sum();
+        let fill_ratio = sprite_area as f32 / (sheet_size.0 * sheet_size.1) as f32;
+        let mut out_image = Png::empty(sheet_size.0, sheet_size.1);
+        let mut mappings = Vec::with_capacity(sprites.len());
+        for ((name, frames), rect) in sprites.into_iter().zip(rects.into_iter()) {
+            let frame_info = FramesInfo::from_rect_pack(sheet_size, &frames, config.padding, rect)?;
+            frames.draw(&mut out_image, rect.x as usize, rect.y as usize, config.padding, config.extrude)?;
+            mappings.push((SpriteSheetFrameId::new(name, sprite_sheet), frame_info));
         }
 
         Ok(PackedSpriteSheet {
             image: out_image,
             mappings,
+            fill_ratio,
         })
     }
 
+    // Starts from the smallest size that could hold every sprite and grows the shorter unfixed side until they fit.
+    fn pack(config: &SheetConfig, sprites: &[(String, SpriteFrames)], sprite_sheet: NamedSpriteSheet) -> StatusOr<((usize, usize), Vec<Rect>)> {
+        let sizes: Vec<(usize, usize)> = sprites.iter()
+            .map(|(_, frames)| frames.packed_size(config.padding))
+            .collect();
+
+        let area: usize = sizes.iter().map(|(width, height)| width * height).sum();
+        let widest = sizes.iter().map(|size| size.0).max().unwrap_or(1);
+        let tallest = sizes.iter().map(|size| size.1).max().unwrap_or(1);
+        let mut width = config.width
+            .unwrap_or_else(|| Self::round_size(config, widest.max((area as f64).sqrt().ceil() as usize)));
+        let mut height = config.height
+            .unwrap_or_else(|| Self::round_size(config, tallest.max((area + width - 1) / width)));
+
+        loop {
+            if let Some(rects) = Self::try_pack(width, height, &sizes) {
+                return Ok(((width, height), rects));
+            }
+
+            let grow_width = match (config.width, config.height) {
+                (Some(_), Some(_)) => return Err(format!("Sprites in {:?} don't fit in its {}x{} sheet. Leave out width and height to size it automatically.", sprite_sheet, width, height)),
+                (Some(_), None) => false,
+                (None, Some(_)) => true,
+                (None, None) => width <= height,
+            };
+            let grown = if grow_width { &mut width } else { &mut height };
+            *grown = if config.power_of_two { *grown * 2 } else { *grown + (*grown / 16).max(1) };
+            if *grown > MAX_SHEET_SIZE {
+                return Err(format!("Sprites in {:?} don't fit in a sheet under {}x{}", sprite_sheet, MAX_SHEET_SIZE, MAX_SHEET_SIZE));
+            }
+        }
+    }
+
+    fn try_pack(width: usize, height: usize, sizes: &[(usize, usize)]) -> Option<Vec<Rect>> {
+        let mut packer = DensePacker::new(width as i32, height as i32);
+        sizes.iter()
+            .map(|(sprite_width, sprite_height)| packer.pack(*sprite_width as i32, *sprite_height as i32, false))
+            .collect()
+    }
+
+    fn round_size(config: &SheetConfig, size: usize) -> usize {
+        let size = size.max(1);
+        if config.power_of_two {
+            size.next_power_of_two()
+        } else {
+            size
+        }
+    }
+
     fn base_directory(images_dir: &PathBuf, sprite_sheet: NamedSpriteSheet) -> PathBuf {
         images_dir.join(sprite_sheet.to_directory_basename())
     }
 
-    fn read_images_with_identifiers(sprite_sheet_dir: PathBuf) -> StatusOr<Vec<(String, Png)>> {
+    fn read_images_with_identifiers(sprite_sheet_dir: &PathBuf) -> StatusOr<Vec<(String, Png)>> {
         let mut images = vec!();
-        for entry in sprite_sheet_dir.read_dir().map_err(|err| format!("Err reading dir {:?}: {}", sprite_sheet_dir, err))? {
-            let entry = entry.map_err(|err| format!("Err reading entry: {}", err))?;
-            let file_name = entry.file_name().into_string().map_err(|err| format!("Err retrieving filename: {:?}", err))?;
-            images.push((file_name, Png::from_file(&entry.path())?));
+        for file_name in file::util::files_in_dir_ending_with(&sprite_sheet_dir, ".png")? {
+            let path = sprite_sheet_dir.join(&file_name);
+            images.push((file_name, Png::from_file(&path)?));
         }
 
-        images.sort_by(|a, b| {
-            let (a_width, a_height) = a.1.size();
-            let (b_width, b_height) = b.1.size();
-
-            let a_size = a_width * a_height;
-            let b_size = b_width * b_height;
-
-            // Sort descending.
-            b_size.cmp(&a_size)
-        });
-
+        // By name, so sprites of equal size always pack the same way.
+        images.sort_by(|a, b| a.0.cmp(&b.0));
         Ok(images)
     }
 }
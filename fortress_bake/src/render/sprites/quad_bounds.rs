@@ -0,0 +1,25 @@
+use crate::render::attribute;
+use glm;
+
+// The part of a sprite's quad a trimmed frame covers, in half sizes from the quad's center.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct QuadBounds {
+    pub bottom_left: glm::Vec2,
+    pub top_right: glm::Vec2,
+}
+
+impl QuadBounds {
+    pub fn full() -> QuadBounds {
+        QuadBounds {
+            bottom_left: glm::vec2(-1.0, -1.0),
+            top_right: glm::vec2(1.0, 1.0),
+        }
+    }
+}
+
+impl attribute::KnownComponent for QuadBounds {
+    fn component() -> (attribute::NumComponents, attribute::ComponentType) {
+        (attribute::NumComponents::S4, attribute::ComponentType::Float)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    app::StatusOr,
+    render::{
+        Png,
+        SpriteConfig,
+    },
+};
+
+// A sprite strip cut into its frames. Trimming removes the transparent border every frame shares, so the
+// frames stay the same size and can still be found on a regular grid.
+pub struct SpriteFrames {
+    pub frames: Vec<Png>,
+    pub num_frames_horizontal: usize,
+    pub num_frames_vertical: usize,
+    pub frame_width: usize,
+    pub frame_height: usize,
+    // Pixels cut off each side of every frame: (left, top, right, bottom).
+    pub trim: (usize, usize, usize, usize),
+}
+
+impl SpriteFrames {
+    pub fn new(image: &Png, sprite: &SpriteConfig, trim: bool) -> StatusOr<SpriteFrames> {
+        let (image_width, image_height) = image.size();
+        let num_frames_horizontal = image_width / sprite.frame_width;
+        let num_frames_vertical = image_height / sprite.frame_height;
+        if num_frames_horizontal == 0 || num_frames_vertical == 0 {
+            return Err(format!("{}x{} frames don't fit in a {}x{} image", sprite.frame_width, sprite.frame_height, image_width, image_height));
+        }
+
+        let mut frames = Vec::with_capacity(num_frames_horizontal * num_frames_vertical);
+        for frame_y in 0..num_frames_vertical {
+            for frame_x in 0..num_frames_horizontal {
+                frames.push(image.crop(frame_x * sprite.frame_width, frame_y * sprite.frame_height, sprite.frame_width, sprite.frame_height)?);
+            }
+        }
+
+        Self::from_grid(frames, num_frames_horizontal, num_frames_vertical, sprite.frame_width, sprite.frame_height, trim)
+    }
+
+    // For frames already cut apart, such as imported ones. They're laid out in the squarest grid they fill exactly, since
+    // frame numbers wrap around the whole grid.
+    pub fn from_frames(frames: Vec<Png>, trim: bool) -> StatusOr<SpriteFrames> {
+        let (frame_width, frame_height) = frames.first().ok_or("No frames")?.size();
+        if let Some(frame) = frames.iter().find(|frame| frame.size() != (frame_width, frame_height)) {
+            let (width, height) = frame.size();
+            return Err(format!("Frames are {}x{} and {}x{}, but must all be the same size", frame_width, frame_height, width, height));
+        }
+
+        let num_frames_vertical = (1..=frames.len())
+            .take_while(|rows| rows * rows <= frames.len())
+            .filter(|rows| frames.len() % rows == 0)
+            .last()
+            .unwrap_or(1);
+        let num_frames_horizontal = frames.len() / num_frames_vertical;
+        Self::from_grid(frames, num_frames_horizontal, num_frames_vertical, frame_width, frame_height, trim)
+    }
+
+    pub fn trimmed_size(&self) -> (usize, usize) {
+        let (left, top, right, bottom) = self.trim;
+        (self.frame_width - left - right, self.frame_height - top - bottom)
+    }
+
+    // Room the frames need in the sheet with padding on every side of each frame.
+    pub fn packed_size(&self, padding: usize) -> (usize, usize) {
+        let (width, height) = self.trimmed_size();
+        (self.num_frames_horizontal * (width + 2 * padding), self.num_frames_vertical * (height + 2 * padding))
+    }
+
+    pub fn draw(self, out: &mut Png, left: usize, top: usize, padding: usize, extrude: bool) -> StatusOr<()> {
+        let (width, height) = self.trimmed_size();
+        let num_frames_horizontal = self.num_frames_horizontal;
+        for (index, frame) in self.frames.into_iter().enumerate() {
+            let frame_left = left + (index % num_frames_horizontal) * (width + 2 * padding) + padding;
+            let frame_top = top + (index / num_frames_horizontal) * (height + 2 * padding) + padding;
+            out.overwrite(frame, frame_left, frame_top)?;
+            if extrude {
+                out.extrude(frame_left, frame_top, width, height, padding);
+            }
+        }
+        Ok(())
+    }
+
+    fn from_grid(frames: Vec<Png>,
+                 num_frames_horizontal: usize,
+                 num_frames_vertical: usize,
+                 frame_width: usize,
+                 frame_height: usize,
+                 trim: bool) -> StatusOr<SpriteFrames> {
+        let mut sprite_frames = SpriteFrames {
+            frames,
+            num_frames_horizontal,
+            num_frames_vertical,
+            frame_width,
+            frame_height,
+            trim: (0, 0, 0, 0),
+        };
+        if trim {
+            sprite_frames.trim()?;
+        }
+        Ok(sprite_frames)
+    }
+
+    fn trim(&mut self) -> StatusOr<()> {
+        let bounds = self.frames.iter()
+            .filter_map(Png::opaque_bounds)
+            .fold(None, |union: Option<(usize, usize, usize, usize)>, (left, top, right, bottom)| Some(match union {
+                None => (left, top, right, bottom),
+                Some(union) => (union.0.min(left), union.1.min(top), union.2.max(right), union.3.max(bottom)),
+            }));
+
+        // Fully transparent sprites keep their size.
+        if let Some((left, top, right, bottom)) = bounds {
+            let (width, height) = (right - left + 1, bottom - top + 1);
+            self.frames = self.frames.iter()
+                .map(|frame| frame.crop(left, top, width, height))
+                .collect::<StatusOr<Vec<Png>>>()?;
+            self.trim = (left, top, self.frame_width - right - 1, self.frame_height - bottom - 1);
+        }
+        Ok(())
+    }
+}
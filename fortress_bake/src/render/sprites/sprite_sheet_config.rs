@@ -1,15 +1,38 @@
-use crate::render::{
-    NamedSpriteSheet,
-    TextureStyle,
+use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
+    render::{
+        AnimationClip,
+        NamedSpriteSheet,
+        TextureStyle,
+    },
 };
 use std::collections::HashMap;
 
 #[derive(Clone, Deserialize)]
 pub struct SheetConfig {
-    pub width: usize,
-    pub height: usize,
+    // Fixes the sheet size. Left out, the bake picks the smallest size that fits every sprite.
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+    // Rounds picked sizes up to powers of two.
+    #[serde(default)]
+    pub power_of_two: bool,
+    // Cuts away the transparent border every frame of a sprite shares.
+    #[serde(default)]
+    pub trim: bool,
+    // Pixels kept clear around each frame so filtering doesn't pick up its neighbours.
+    #[serde(default)]
+    pub padding: usize,
+    // Fills the padding with copies of each frame's edge pixels instead.
+    #[serde(default)]
+    pub extrude: bool,
     pub style: TextureStyle,
     pub sprites: HashMap<String, SpriteConfig>,
+    // Animations over this sheet's sprites, by name.
+    #[serde(default)]
+    pub clips: HashMap<String, AnimationClip>,
 }
 
 #[derive(Clone, Deserialize)]
@@ -22,3 +45,42 @@ pub struct SpriteConfig {
 pub struct SpriteSheetConfig {
     pub sheets: HashMap<NamedSpriteSheet, SheetConfig>
 }
+
+impl SpriteSheetConfig {
+    pub fn clip(&self, sprite_sheet: NamedSpriteSheet, name: &str) -> Option<&AnimationClip> {
+        self.sheets.get(&sprite_sheet)?.clips.get(name)
+    }
+}
+
+impl Validate for SpriteSheetConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        for (name, sheet) in self.sheets.iter() {
+            validator.nested(format!("sheets[{:?}]", name), sheet);
+        }
+    }
+}
+
+impl Validate for SheetConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        if let Some(width) = self.width {
+            validator.positive("width", width);
+        }
+        if let Some(height) = self.height {
+            validator.positive("height", height);
+        }
+        validator.require("extrude", !self.extrude || self.padding > 0, "needs padding to extrude into");
+        for (name, sprite) in self.sprites.iter() {
+            validator.nested(format!("sprites[{}]", name), sprite);
+        }
+        for (name, clip) in self.clips.iter() {
+            validator.nested(format!("clips[{}]", name), clip);
+        }
+    }
+}
+
+impl Validate for SpriteConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("frame_width", self.frame_width);
+        validator.positive("frame_height", self.frame_height);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    app::StatusOr,
+    render::{
+        SerializableBitmap,
+        Texel,
+    },
+    text::{
+        CharRasterInfo,
+        GlyphId,
+        GlyphInfo,
+        PackedGlyphSheet,
+        RasterSize,
+    },
+};
+use glm;
+
+// glm types don't serialize, so glyphs are stored as plain floats.
+#[derive(Serialize, Deserialize)]
+struct BakedGlyph {
+    character: char,
+    size: RasterSize,
+    texel_bottom_left: (f32, f32),
+    texel_top_right: (f32, f32),
+    raster_dimensions: (f32, f32),
+    advance_width: f32,
+    left_side_bearing: f32,
+    height_offset: f32,
+}
+
+// The atlas itself is stored next to this as raw bytes, which loads far faster than a RON bitmap.
+#[derive(Serialize, Deserialize)]
+pub struct BakedGlyphSheetConfig {
+    atlas_size: (usize, usize),
+    glyphs: Vec<BakedGlyph>,
+}
+
+impl BakedGlyphSheetConfig {
+    pub fn new(packed: &PackedGlyphSheet) -> Self {
+        let glyphs = packed.mappings.iter()
+            .map(|(glyph_id, glyph_info)| {
+                let texel = glyph_info.texel();
+                let raster_info = glyph_info.raster_info();
+                BakedGlyph {
+                    character: glyph_id.character,
+                    size: glyph_id.size,
+                    texel_bottom_left: (texel.bottom_left.x, texel.bottom_left.y),
+                    texel_top_right: (texel.top_right.x, texel.top_right.y),
+                    raster_dimensions: (raster_info.raster_dimensions.x, raster_info.raster_dimensions.y),
+                    advance_width: raster_info.advance_width,
+                    left_side_bearing: raster_info.left_side_bearing,
+                    height_offset: raster_info.height_offset,
+                }
+            })
+            .collect();
+
+        BakedGlyphSheetConfig {
+            atlas_size: packed.image.size(),
+            glyphs,
+        }
+    }
+
+    // Rebuilds the sheet from the baked config and atlas without touching any fonts.
+    pub fn into_packed(self, raw_atlas: &[u8]) -> StatusOr<PackedGlyphSheet> {
+        let image = SerializableBitmap::from_raw(raw_atlas.to_vec(), self.atlas_size.0, self.atlas_size.1)?;
+        let mappings = self.glyphs
+            .into_iter()
+            .map(|glyph| {
+                let texel = Texel {
+                    bottom_left: glm::vec2(glyph.texel_bottom_left.0, glyph.texel_bottom_left.1),
+                    top_right: glm::vec2(glyph.texel_top_right.0, glyph.texel_top_right.1),
+                };
+                let char_raster_info = CharRasterInfo {
+                    raster_dimensions: glm::vec2(glyph.raster_dimensions.0, glyph.raster_dimensions.1),
+                    advance_width: glyph.advance_width,
+                    left_side_bearing: glyph.left_side_bearing,
+                    height_offset: glyph.height_offset,
+                };
+                (GlyphId::new(glyph.character, glyph.size), GlyphInfo::new(texel, char_raster_info))
+            })
+            .collect();
+
+        Ok(PackedGlyphSheet {
+            image,
+            mappings,
+        })
+    }
+
+    pub fn from_slice(slice: &[u8]) -> StatusOr<Self> {
+        ron::de::from_bytes(slice)
+            .map_err(|e| format!("Baked glyph sheet read err: {:?}", e))
+    }
+}
@@ -11,6 +11,13 @@ pub struct GlyphInfo {
 }
 
 impl GlyphInfo {
+    pub fn new(texel: Texel, char_raster_info: CharRasterInfo) -> Self {
+        GlyphInfo {
+            texel,
+            char_raster_info,
+        }
+    }
+
     pub fn from(char_raster_info: CharRasterInfo, atlas_size: (usize, usize), rect: Rect) -> Self {
         let left_center = rect.x as f32 + 0.5;
         let bottom_center = (atlas_size.1 as i32 - rect.y) as f32 - 0.5;
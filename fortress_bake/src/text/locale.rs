@@ -1,4 +1,12 @@
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+use enum_iterator::Sequence;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Sequence)]
 pub enum Locale {
     Murica,
-}
\ No newline at end of file
+}
+
+impl Locale {
+    pub fn all_values() -> impl Iterator<Item=Locale> {
+        enum_iterator::all::<Self>()
+    }
+}
@@ -1,4 +1,6 @@
+pub mod baked_glyph_sheet_config;
 pub mod char_raster_info;
+#[cfg(feature = "rasterize")]
 pub mod font;
 pub mod glyph_id;
 pub mod glyph_info;
@@ -8,7 +10,9 @@ pub mod packed_glyph_sheet;
 pub mod raster_size;
 pub mod text_config;
 
+pub use self::baked_glyph_sheet_config::BakedGlyphSheetConfig;
 pub use self::char_raster_info::CharRasterInfo;
+#[cfg(feature = "rasterize")]
 pub use self::font::Font;
 pub use self::glyph_id::GlyphId;
 pub use self::glyph_info::GlyphInfo;
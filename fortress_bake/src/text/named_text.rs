@@ -1,6 +1,43 @@
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Deserialize)]
+use enum_iterator::Sequence;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Sequence)]
 pub enum NamedText {
     FpsPrefix,
     GameInAlpha,
     SkullCounterPrefix,
+    TitleHeading,
+    TitlePrompt,
+    PausedHeading,
+    PausedPrompt,
+    QuitHeading,
+    QuitPrompt,
+    VictoryHeading,
+    ContinuePrompt,
+    ProfileFrame,
+    ProfileEvents,
+    ProfileSimulation,
+    ProfileWorldPreUpdate,
+    ProfilePhysicsStep,
+    ProfileProcessContacts,
+    ProfileWorldPostUpdate,
+    ProfileParticleUpdate,
+    ProfileRenderUpdate,
+    ProfileDrawWorld,
+    ProfileDrawSprites,
+    ProfileDrawHex,
+    ProfileDrawParticles,
+    ProfileDrawText,
+    ProfileBloom,
+    ProfilePresent,
+    CounterSimulationSteps,
+    CounterPhysicsBodies,
+    CounterSpritesQueued,
+    CounterLights,
+    CounterParticles,
+}
+
+impl NamedText {
+    pub fn all_values() -> impl Iterator<Item=NamedText> {
+        enum_iterator::all::<Self>()
+    }
 }
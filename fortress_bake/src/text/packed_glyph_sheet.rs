@@ -1,22 +1,27 @@
+use crate::{
+    render::SerializableBitmap,
+    text::{
+        GlyphId,
+        GlyphInfo,
+    }
+};
+#[cfg(feature = "rasterize")]
 use crate::{
     app::StatusOr,
     file,
-    render::SerializableBitmap,
     text::{
         CharRasterInfo,
         Font,
-        GlyphId,
-        GlyphInfo,
         TextConfig,
         RasterSize,
     }
 };
+#[cfg(feature = "rasterize")]
 use rect_packer::DensePacker;
+use std::collections::HashMap;
+#[cfg(feature = "rasterize")]
 use std::{
-    collections::{
-        HashMap,
-        HashSet,
-    },
+    collections::HashSet,
     path::PathBuf,
 };
 
@@ -25,6 +30,7 @@ pub struct PackedGlyphSheet {
     pub mappings: HashMap<GlyphId, GlyphInfo>,
 }
 
+#[cfg(feature = "rasterize")]
 impl PackedGlyphSheet {
     pub fn new(config: &TextConfig, fonts_dir: &PathBuf) -> StatusOr<Self> {
         let fonts = Self::load_all_fonts(fonts_dir)?;
@@ -74,6 +80,12 @@ impl PackedGlyphSheet {
             }
         }
 
+        for size in config.printable_ascii_sizes.iter() {
+            for character in (0x20u8..=0x7E).map(char::from) {
+                all.insert(GlyphId::new(character, *size));
+            }
+        }
+
         for text_mapping in config.localized_text.values() {
             for (named_text, string) in text_mapping.iter() {
                 if let Some(raster_sizes) = config.text_sizes.get(named_text) {
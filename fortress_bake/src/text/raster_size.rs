@@ -1,6 +1,6 @@
 use enum_iterator::Sequence;
 
-#[derive(Copy, Clone, Deserialize, Debug, PartialEq, Eq, PartialOrd, Hash, Sequence)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Hash, Sequence)]
 pub enum RasterSize {
     Small,
     Medium,
@@ -1,4 +1,8 @@
 use crate::{
+    file::{
+        ConfigValidator,
+        Validate,
+    },
     render::TextureStyle,
     text::{
         Locale,
@@ -17,5 +21,22 @@ pub struct TextConfig {
     pub world_to_glyph_length_ratio: f32,
     pub raster_sizes: HashMap<RasterSize, f32>,
     pub text_sizes: HashMap<NamedText, Vec<RasterSize>>,
+    // Sizes that get every printable ASCII character, for text that isn't known ahead of time.
+    pub printable_ascii_sizes: Vec<RasterSize>,
     pub localized_text: HashMap<Locale, HashMap<NamedText, String>>,
 }
+
+impl Validate for TextConfig {
+    fn validate(&self, validator: &mut ConfigValidator) {
+        validator.positive("texture_atlas_size.0", self.texture_atlas_size.0);
+        validator.positive("texture_atlas_size.1", self.texture_atlas_size.1);
+        validator.positive("world_to_glyph_length_ratio", self.world_to_glyph_length_ratio);
+        for size in RasterSize::all_values() {
+            match self.raster_sizes.get(&size) {
+                None => validator.require("raster_sizes", false, format!("is missing {:?}", size)),
+                Some(pixels) => validator.positive(format!("raster_sizes[{:?}]", size), *pixels),
+            }
+        }
+        validator.require("localized_text", self.localized_text.contains_key(&self.current_locale), "has no entry for current_locale");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod world_config;
+
+pub use self::world_config::WorldConfig;
@@ -0,0 +1,4 @@
+#[derive(Deserialize, Validate)]
+pub struct WorldConfig {
+    pub clear_color: (f32, f32, f32),
+}
@@ -0,0 +1,23 @@
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    DeriveInput,
+};
+
+// For configs with nothing to check beyond deserializing. Expands to an impl that uses the no-op default.
+#[proc_macro_derive(Validate)]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let expanded = quote! {
+        impl #impl_generics ::fortress_bake::file::Validate for #name #ty_generics #where_clause {}
+    };
+    expanded.into()
+}
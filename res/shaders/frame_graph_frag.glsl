@@ -0,0 +1,10 @@
+#version 330 core
+layout (location = 0) out vec4 frag_color;
+
+in GS_OUT {
+    vec4 rgba_color;
+} fs_in;
+
+void main() {
+    frag_color = fs_in.rgba_color;
+}
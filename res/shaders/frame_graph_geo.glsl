@@ -0,0 +1,33 @@
+#version 330 core
+layout (points) in;
+layout (triangle_strip, max_vertices = 4) out;
+
+in VS_OUT {
+    vec4 rect;
+    vec4 rgba_color;
+} gs_in[];
+
+out GS_OUT {
+    vec4 rgba_color;
+} gs_out;
+
+uniform vec2 screen_window_size;
+
+void EmitV(vec2 pixel_position) {
+    // Pixels to normalized device coordinates.
+    gl_Position = vec4(2.0 * pixel_position / screen_window_size - 1.0, 0.0, 1.0);
+    EmitVertex();
+}
+
+void main() {
+    gs_out.rgba_color = gs_in[0].rgba_color;
+
+    vec2 bot_left = gs_in[0].rect.xy;
+    vec2 size = gs_in[0].rect.zw;
+
+    EmitV(bot_left + vec2(0.0, size.y));
+    EmitV(bot_left + size);
+    EmitV(bot_left);
+    EmitV(bot_left + vec2(size.x, 0.0));
+    EndPrimitive();
+}
@@ -0,0 +1,14 @@
+#version 330 core
+layout (location = 0) in vec4 rect; // xy = bottom_left, zw = size, in pixels
+layout (location = 1) in vec4 rgba_color;
+
+out VS_OUT {
+    vec4 rect;
+    vec4 rgba_color;
+} vs_out;
+
+void main() {
+    gl_Position = vec4(rect.xy, 0.0, 1.0);
+    vs_out.rect = rect;
+    vs_out.rgba_color = rgba_color;
+}
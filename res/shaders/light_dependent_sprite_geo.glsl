@@ -8,6 +8,8 @@ in VS_OUT {
   vec2 texel_bottom_left;
   vec2 texel_top_right;
   vec2 unit_world_rotation_xz;
+  vec2 quad_bottom_left;
+  vec2 quad_top_right;
 } gs_in[];
 
 out GS_OUT {
@@ -58,10 +60,13 @@ void EmitQuad() {
   vec3 half_size_along_camera_right = gs_in[0].half_size.x * rot_camera_right;
   vec3 half_size_along_camera_up = gs_in[0].half_size.y * rot_camera_up;
 
-  vec3 world_bottom_left = world_bottom_center - half_size_along_camera_right - half_size_along_camera_up;
-  vec3 world_bottom_right = world_bottom_center + half_size_along_camera_right - half_size_along_camera_up;
-  vec3 world_top_left = world_bottom_center - half_size_along_camera_right + half_size_along_camera_up;
-  vec3 world_top_right = world_bottom_center + half_size_along_camera_right + half_size_along_camera_up;
+  // Trimmed frames only cover part of the quad.
+  vec2 quad_bottom_left = gs_in[0].quad_bottom_left;
+  vec2 quad_top_right = gs_in[0].quad_top_right;
+  vec3 world_bottom_left = world_bottom_center + quad_bottom_left.x * half_size_along_camera_right + quad_bottom_left.y * half_size_along_camera_up;
+  vec3 world_bottom_right = world_bottom_center + quad_top_right.x * half_size_along_camera_right + quad_bottom_left.y * half_size_along_camera_up;
+  vec3 world_top_left = world_bottom_center + quad_bottom_left.x * half_size_along_camera_right + quad_top_right.y * half_size_along_camera_up;
+  vec3 world_top_right = world_bottom_center + quad_top_right.x * half_size_along_camera_right + quad_top_right.y * half_size_along_camera_up;
 
   vec2 texel_top_left = vec2(gs_in[0].texel_bottom_left.x, gs_in[0].texel_top_right.y);
   vec2 texel_bottom_right = vec2(gs_in[0].texel_top_right.x, gs_in[0].texel_bottom_left.y);
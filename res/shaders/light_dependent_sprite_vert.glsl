@@ -3,6 +3,7 @@ layout (location = 0) in vec3 world_center_position;
 layout (location = 1) in vec2 half_size;
 layout (location = 2) in vec4 texel_coords; // xy = bottom_left, zw = top_right.
 layout (location = 3) in vec2 unit_world_rotation_xz;
+layout (location = 4) in vec4 quad_bounds; // xy = bottom_left, zw = top_right, in half sizes from the center.
 
 out VS_OUT {
     vec3 world_center_position;
@@ -10,6 +11,8 @@ out VS_OUT {
     vec2 texel_bottom_left;
     vec2 texel_top_right;
     vec2 unit_world_rotation_xz;
+    vec2 quad_bottom_left;
+    vec2 quad_top_right;
 } vs_out;
 
 void main() {
@@ -23,4 +26,6 @@ void main() {
     vs_out.texel_top_right = vec2(texel_coords.z, 1.0 - texel_coords.w);
 
     vs_out.unit_world_rotation_xz = unit_world_rotation_xz;
+    vs_out.quad_bottom_left = quad_bounds.xy;
+    vs_out.quad_top_right = quad_bounds.zw;
 }